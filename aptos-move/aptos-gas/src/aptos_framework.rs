@@ -5,7 +5,15 @@ use framework::natives::GasParameters;
 
 crate::natives::define_gas_parameters_for_natives!(GasParameters, "aptos_framework", [
     [.account.create_address.base_cost, "account.create_address.base", 1],
+    [.account.create_object_address.base_cost, "account.create_object_address.base", 1],
+    [.account.create_object_address.per_byte_cost, "account.create_object_address.per_byte", 1],
     [.account.create_signer.base_cost, "account.create_signer.base", 1],
+    [.account.create_signers.base_cost, "account.create_signers.base", 1],
+    [.account.create_signers.per_signer_cost, "account.create_signers.per_signer", 1],
+    [.account.auth_key_from_ed25519_pubkey.base_cost, "account.auth_key_from_ed25519_pubkey.base", 1],
+    [.account.auth_key_from_ed25519_pubkey.per_byte_cost, "account.auth_key_from_ed25519_pubkey.per_byte", 1],
+    [.account.auth_key_from_multi_ed25519_pubkey.base_cost, "account.auth_key_from_multi_ed25519_pubkey.base", 1],
+    [.account.auth_key_from_multi_ed25519_pubkey.per_byte_cost, "account.auth_key_from_multi_ed25519_pubkey.per_byte", 1],
 
     [.bls12381.base_cost, "bls12381.base", 1],
 
@@ -25,6 +33,18 @@ crate::natives::define_gas_parameters_for_natives!(GasParameters, "aptos_framewo
     [.bls12381.per_msg_hashing_cost, "bls12381.per_msg_hashing", 1],
     [.bls12381.per_byte_hashing_cost, "bls12381.per_byte_hashing", 1],
 
+    [.bls12381.per_distinct_msg_check_cost, "bls12381.per_distinct_msg_check", 1],
+    [.bls12381.per_distinct_msg_byte_check_cost, "bls12381.per_distinct_msg_byte_check", 1],
+
+    [.bn254.g1_add.base_cost, "bn254.g1_add.base", 1],
+    [.bn254.g1_mul.base_cost, "bn254.g1_mul.base", 1],
+    [.bn254.g2_add.base_cost, "bn254.g2_add.base", 1],
+    [.bn254.g2_mul.base_cost, "bn254.g2_mul.base", 1],
+    [.bn254.pairing_check.base_cost, "bn254.pairing_check.base", 1],
+    [.bn254.pairing_check.per_g1_deserialize_cost, "bn254.pairing_check.per_g1_deserialize", 1],
+    [.bn254.pairing_check.per_g2_deserialize_cost, "bn254.pairing_check.per_g2_deserialize", 1],
+    [.bn254.pairing_check.per_pairing_cost, "bn254.pairing_check.per_pairing", 1],
+
     [.ed25519.base_cost, "signature.base", 1],
     [.ed25519.per_pubkey_deserialize_cost, "signature.per_pubkey_deserialize", 1],
     [.ed25519.per_pubkey_small_order_check_cost, "signature.per_pubkey_small_order_check", 1],
@@ -35,22 +55,91 @@ crate::natives::define_gas_parameters_for_natives!(GasParameters, "aptos_framewo
 
     [.secp256k1.base_cost, "secp256k1.base", 1],
     [.secp256k1.ecdsa_recover_cost, "secp256k1.ecdsa_recover", 1],
+    [.secp256k1.eth_address_cost, "secp256k1.eth_address", 1],
+
+    [.groth16.base_cost, "groth16.base", 1],
+    [.groth16.per_vk_deserialize_cost, "groth16.per_vk_deserialize", 1],
+    [.groth16.per_vk_point_deserialize_cost, "groth16.per_vk_point_deserialize", 1],
+    [.groth16.per_proof_deserialize_cost, "groth16.per_proof_deserialize", 1],
+    [.groth16.per_public_input_cost, "groth16.per_public_input", 1],
+    [.groth16.per_pairing_cost, "groth16.per_pairing", 1],
 
     [.hash.sip_hash.base_cost, "hash.sip_hash.base", 1],
     [.hash.sip_hash.unit_cost, "hash.sip_hash.unit", 1],
+    [.hash.sha256d.base_cost, "hash.sha256d.base", 1],
+    [.hash.sha256d.per_byte_cost, "hash.sha256d.per_byte", 1],
+    [.hash.eth_selector.base_cost, "hash.eth_selector.base", 1],
+    [.hash.eth_selector.per_byte_cost, "hash.eth_selector.per_byte", 1],
+    [.hash.crc32.base_cost, "hash.crc32.base", 1],
+    [.hash.crc32.per_byte_cost, "hash.crc32.per_byte", 1],
+    [.hash.crc32c.base_cost, "hash.crc32c.base", 1],
+    [.hash.crc32c.per_byte_cost, "hash.crc32c.per_byte", 1],
+    [.hash.hash_value.base_cost, "hash.hash_value.base", 1],
+    [.hash.hash_value.per_byte_serialize_cost, "hash.hash_value.per_byte_serialize", 1],
+    [.hash.hash_value.per_byte_hash_cost, "hash.hash_value.per_byte_hash", 1],
+    [.hash.verify_merkle_proof.base_cost, "hash.verify_merkle_proof.base", 1],
+    [.hash.verify_merkle_proof.per_level_cost, "hash.verify_merkle_proof.per_level", 1],
+    [.hash.verify_merkle_proof.per_byte_hash_cost, "hash.verify_merkle_proof.per_byte_hash", 1],
+    [.hash.merkle_node_sorted.base_cost, "hash.merkle_node_sorted.base", 1],
+    [.hash.merkle_node_sorted.per_byte_hash_cost, "hash.merkle_node_sorted.per_byte_hash", 1],
+    [.hash.accumulator_leaf_hash.base_cost, "hash.accumulator_leaf_hash.base", 1],
+    [.hash.accumulator_leaf_hash.per_byte_cost, "hash.accumulator_leaf_hash.per_byte", 1],
+    [.hash.accumulator_internal_hash.base_cost, "hash.accumulator_internal_hash.base", 1],
 
     [.type_info.type_of.base_cost, "type_info.type_of.base", 1],
     [.type_info.type_of.unit_cost, "type_info.type_of.unit", 1],
     [.type_info.type_name.base_cost, "type_info.type_name.base", 1],
     [.type_info.type_name.unit_cost, "type_info.type_name.unit", 1],
+    [.type_info.parse_type_tag.base_cost, "type_info.parse_type_tag.base", 1],
+    [.type_info.parse_type_tag.per_byte_cost, "type_info.parse_type_tag.per_byte", 1],
 
     [.util.from_bytes.base_cost, "util.from_bytes.base", 1],
     [.util.from_bytes.unit_cost, "util.from_bytes.unit", 1],
+    [.util.bytes_equal_constant_time.base_cost, "util.bytes_equal_constant_time.base", 1],
+    [.util.bytes_equal_constant_time.per_byte_cost, "util.bytes_equal_constant_time.per_byte", 1],
+    [.util.ct_select.base_cost, "util.ct_select.base", 1],
+    [.util.ct_select.per_byte_cost, "util.ct_select.per_byte", 1],
+    [.util.to_hex.base_cost, "util.to_hex.base", 1],
+    [.util.to_hex.per_byte_cost, "util.to_hex.per_byte", 1],
+    [.util.from_hex.base_cost, "util.from_hex.base", 1],
+    [.util.from_hex.per_byte_cost, "util.from_hex.per_byte", 1],
+    [.util.base64_encode.base_cost, "util.base64_encode.base", 1],
+    [.util.base64_encode.per_byte_cost, "util.base64_encode.per_byte", 1],
+    [.util.base64_decode.base_cost, "util.base64_decode.base", 1],
+    [.util.base64_decode.per_byte_cost, "util.base64_decode.per_byte", 1],
+    [.util.reverse_bytes.base_cost, "util.reverse_bytes.base", 1],
+    [.util.reverse_bytes.per_byte_cost, "util.reverse_bytes.per_byte", 1],
+    [.util.u64_to_bytes.base_cost, "util.u64_to_bytes.base", 1],
+    [.util.u64_from_bytes.base_cost, "util.u64_from_bytes.base", 1],
+    [.util.mul_u128_wide.base_cost, "util.mul_u128_wide.base", 1],
+    [.util.mod_inverse.base_cost, "util.mod_inverse.base", 1],
+    [.util.mod_inverse.per_bit_cost, "util.mod_inverse.per_bit", 1],
+    [.util.mul_div.base_cost, "util.mul_div.base", 1],
+    [.util.bytes_slice.base_cost, "util.bytes_slice.base", 1],
+    [.util.bytes_slice.per_byte_cost, "util.bytes_slice.per_byte", 1],
+    [.util.bytes_concat.base_cost, "util.bytes_concat.base", 1],
+    [.util.bytes_concat.per_byte_cost, "util.bytes_concat.per_byte", 1],
+    [.util.rotate.base_cost, "util.rotate.base", 1],
+    [.util.bit_count.base_cost, "util.bit_count.base", 1],
+    [.util.shuffle_in_place.base_cost, "util.shuffle_in_place.base", 1],
+    [.util.shuffle_in_place.per_element_cost, "util.shuffle_in_place.per_element", 1],
+    [.util.random_bytes.base_cost, "util.random_bytes.base", 1],
+    [.util.random_bytes.per_byte_cost, "util.random_bytes.per_byte", 1],
+    [.util.commit_vec.base_cost, "util.commit_vec.base", 1],
+    [.util.commit_vec.per_item_cost, "util.commit_vec.per_item", 1],
+    [.util.commit_vec.per_byte_cost, "util.commit_vec.per_byte", 1],
+    [.util.sort.base_cost, "util.sort.base", 1],
+    [.util.sort.per_comparison_cost, "util.sort.per_comparison", 1],
 
     [.transaction_context.get_script_hash.base_cost, "transaction_context.get_script_hash.base", 1],
+    [.transaction_context.fresh_id.base_cost, "transaction_context.fresh_id.base", 1],
 
     [.code.request_publish.base_cost, "code.request_publish.base", 1],
     [.code.request_publish.unit_cost, "code.request_publish.unit", 1],
+    [.code.module_friends.base_cost, "code.module_friends.base", 1],
+    [.code.module_friends.per_byte_cost, "code.module_friends.per_byte", 1],
+    [.code.module_bytecode_version.base_cost, "code.module_bytecode_version.base", 1],
+    [.code.module_bytecode_version.per_byte_cost, "code.module_bytecode_version.per_byte", 1],
 
     [.event.write_to_event_store.base_cost, "event.write_to_event_store.base", 1],
     [.event.write_to_event_store.unit_cost, "event.write_to_event_store.unit", 1],
@@ -87,6 +87,8 @@ module aptos_framework::account {
     const EINVALID_PROOF_OF_KNOWLEDGE: u64 = 9;
     /// The caller does not have a digital-signature-based capability to call this function
     const ENO_CAPABILITY: u64 = 10;
+    /// The public key is malformed
+    const EMALFORMED_PUBLIC_KEY: u64 = 11;
 
     /// Prologue errors. These are separated out from the other errors in this
     /// module since they are mapped separately to major VM statuses, and are
@@ -110,6 +112,29 @@ module aptos_framework::account {
     native fun create_address(bytes: vector<u8>): address;
     native fun create_signer(addr: address): signer;
 
+    /// Returns the address of the object created by `creator` from `seed`, i.e., `sha3_256(creator | seed | 0xFE)`.
+    /// The trailing scheme byte separates object addresses from resource account addresses, which are derived as
+    /// `sha3_256(creator | seed)`.
+    public native fun create_object_address(creator: address, seed: vector<u8>): address;
+
+    /// Same as calling `create_signer` on each address in `addrs`, in a single native call.
+    native fun create_signers(addrs: vector<address>): vector<signer>;
+
+    #[test_only]
+    public fun create_signers_for_test(addrs: vector<address>): vector<signer> {
+        create_signers(addrs)
+    }
+
+    /// Returns the authentication key of an Ed25519 public key, i.e., `sha3_256(pubkey | 0x00)`.
+    /// Aborts with `EMALFORMED_PUBLIC_KEY` if `pubkey` is not a 32-byte point on the curve.
+    public native fun auth_key_from_ed25519_pubkey(pubkey: vector<u8>): vector<u8>;
+
+    /// Returns the authentication key of a MultiEd25519 public key (the concatenated Ed25519 public keys followed by
+    /// the 1-byte threshold), i.e., `sha3_256(pubkey | 0x01)`.
+    /// Aborts with `EMALFORMED_PUBLIC_KEY` if `pubkey` is malformed, or if its threshold is 0 or larger than its number
+    /// of keys.
+    public native fun auth_key_from_multi_ed25519_pubkey(pubkey: vector<u8>): vector<u8>;
+
     public(friend) fun initialize(
         account: &signer,
         module_addr: address,
@@ -505,6 +530,34 @@ module aptos_framework::account {
         coin::register<AptosCoin>(&resource_account);
     }
 
+    #[test]
+    fun test_create_signers() {
+        let signers = create_signers_for_test(vector[@0x1, @0xcafe, @0x1]);
+        assert!(vector::length(&signers) == 3, 0);
+        assert!(signer::address_of(vector::borrow(&signers, 0)) == @0x1, 1);
+        assert!(signer::address_of(vector::borrow(&signers, 1)) == @0xcafe, 2);
+        assert!(signer::address_of(vector::borrow(&signers, 2)) == @0x1, 3);
+        assert!(vector::is_empty(&create_signers_for_test(vector[])), 4);
+    }
+
+    #[test]
+    fun test_create_object_address() {
+        assert!(
+            create_object_address(@0xcafe, b"seed") ==
+                @0x13d1c291f92b76cbc84a2dafa1c1fc57bf0500ada5d89d3dc8a07641233dd7db,
+            0
+        );
+        assert!(
+            create_object_address(@0xcafe, b"") ==
+                @0x319a75b5a8aff570271587a98231c1dfe20be945bc74aa31e867d1285f2b438c,
+            1
+        );
+
+        let bytes = bcs::to_bytes(&@0xcafe);
+        vector::append(&mut bytes, b"seed");
+        assert!(create_object_address(@0xcafe, b"seed") != create_address(hash::sha3_256(bytes)), 2);
+    }
+
     #[test_only]
     struct DummyResource has key { }
 
@@ -605,4 +658,30 @@ module aptos_framework::account {
         let pk = x"0000000000000000000000000000000000000000000000000000000000000000";
         rotate_authentication_key_ed25519(&alice, test_signature, test_signature, pk, pk);
     }
+
+    #[test]
+    public entry fun test_auth_key_from_pubkey() {
+        let pk = x"d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+        let auth_key = auth_key_from_ed25519_pubkey(pk);
+        assert!(auth_key == x"63c5215e87770d17b9f4cd47c777e322f4eb152cfd2054c1080fd9d57c48913b", 1);
+        vector::push_back(&mut pk, 0);
+        assert!(auth_key == hash::sha3_256(pk), 2);
+
+        // Two keys with a threshold of 1
+        let multi_pk = x"d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c01";
+        let auth_key = auth_key_from_multi_ed25519_pubkey(multi_pk);
+        assert!(auth_key == x"a789a8c9351a08dfb668780e60d13b5b2d4023ba6d63f8e6626c882519388701", 3);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65547)]
+    public entry fun test_auth_key_from_ed25519_pubkey_wrong_length() {
+        auth_key_from_ed25519_pubkey(x"d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65547)]
+    public entry fun test_auth_key_from_multi_ed25519_pubkey_bad_threshold() {
+        auth_key_from_multi_ed25519_pubkey(x"d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a02");
+    }
 }
@@ -3,7 +3,23 @@ spec aptos_framework::account {
         pragma opaque;
     }
 
+    spec create_object_address { // TODO: temporary mockup.
+        pragma opaque;
+    }
+
     spec create_signer { // TODO: temporary mockup.
         pragma opaque;
     }
+
+    spec create_signers { // TODO: temporary mockup.
+        pragma opaque;
+    }
+
+    spec auth_key_from_ed25519_pubkey { // TODO: temporary mockup.
+        pragma opaque;
+    }
+
+    spec auth_key_from_multi_ed25519_pubkey { // TODO: temporary mockup.
+        pragma opaque;
+    }
 }
@@ -1,6 +1,6 @@
 /// This module supports functionality related to code management.
 module aptos_framework::code {
-    use std::string::String;
+    use std::string::{Self, String};
     use std::error;
     use std::signer;
     use std::vector;
@@ -53,6 +53,12 @@ module aptos_framework::code {
         policy: u8
     }
 
+    /// Identifies a module by the address it is published at and its name.
+    struct ModuleId has store, copy, drop {
+        account_address: address,
+        module_name: String,
+    }
+
     /// Package contains duplicate module names with existing modules publised in other packages on this address
     const EMODULE_NAME_CLASH: u64 = 0x1;
 
@@ -65,6 +71,9 @@ module aptos_framework::code {
     /// Cannot delete a module that was published in the same package
     const EMODULE_MISSING: u64 = 0x4;
 
+    /// The bytes are not a valid serialized module
+    const EMODULE_DESERIALIZATION: u64 = 0x5;
+
     /// Whether unconditional code upgrade with no compatibility check is allowed. This
     /// publication mode should only be used for modules which aren't shared with user others.
     /// The developer is responsible for not breaking memory layout of any resources he already
@@ -198,6 +207,40 @@ module aptos_framework::code {
         module_names
     }
 
+    // ----------------------------------------------------------------------
+    // Module Inspection
+
+    /// Returns the friends declared by the serialized module `code`.
+    /// Aborts with `EMODULE_DESERIALIZATION` if `code` is not a valid serialized module.
+    public fun module_friends(code: vector<u8>): vector<ModuleId> {
+        let (addresses, names) = module_friends_internal(code);
+        let friends = vector::empty();
+        let i = 0;
+        while (i < vector::length(&addresses)) {
+            vector::push_back(&mut friends, ModuleId {
+                account_address: *vector::borrow(&addresses, i),
+                module_name: string::utf8(*vector::borrow(&names, i)),
+            });
+            i = i + 1
+        };
+        friends
+    }
+
+    /// Returns the address a module is published at.
+    public fun module_id_address(id: &ModuleId): address {
+        id.account_address
+    }
+
+    /// Returns the name of a module.
+    public fun module_id_name(id: &ModuleId): String {
+        id.module_name
+    }
+
+    /// Returns the bytecode version of the serialized module `code`, read from its header only, e.g., to reject
+    /// modules compiled for an unexpected version. The version is a `u32` in the bytecode format.
+    /// Aborts with `EMODULE_DESERIALIZATION` if `code` does not start with a valid module header.
+    public native fun module_bytecode_version(code: vector<u8>): u64;
+
     /// Native function to initiate module loading
     native fun request_publish(
         owner: address,
@@ -205,4 +248,31 @@ module aptos_framework::code {
         bundle: vector<vector<u8>>,
         policy: u8
     );
+
+    /// Native function to read the friend declarations of a module, as the addresses and the names of the friends.
+    native fun module_friends_internal(code: vector<u8>): (vector<address>, vector<vector<u8>>);
+
+    #[test]
+    #[expected_failure(abort_code = 65541)]
+    fun test_module_friends_invalid_code() {
+        module_friends(x"deadbeef");
+    }
+
+    #[test]
+    fun test_module_bytecode_version() {
+        assert!(module_bytecode_version(x"a11ceb0b05000000") == 5, 1);
+        assert!(module_bytecode_version(x"a11ceb0b04000000060000000001") == 4, 2);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65541)]
+    fun test_module_bytecode_version_invalid_magic() {
+        module_bytecode_version(x"deadbeef05000000");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65541)]
+    fun test_module_bytecode_version_truncated() {
+        module_bytecode_version(x"a11ceb0b0500");
+    }
 }
@@ -2,4 +2,12 @@ spec aptos_framework::code {
     spec request_publish { // TODO: temporary mockup.
         pragma opaque;
     }
+
+    spec module_friends_internal { // TODO: temporary mockup.
+        pragma opaque;
+    }
+
+    spec module_bytecode_version { // TODO: temporary mockup.
+        pragma opaque;
+    }
 }
@@ -1,4 +1,16 @@
 module aptos_framework::transaction_context {
     /// Return the script hash of the current entry function.
     public native fun get_script_hash(): vector<u8>;
+
+    /// Return a fresh ID, unique within the current transaction: IDs are handed out in increasing order, starting
+    /// from 0 for every transaction. Combine it with a transaction-specific value (e.g., the sender's sequence
+    /// number) for IDs that are unique across transactions.
+    public native fun fresh_id(): u64;
+
+    #[test]
+    fun test_fresh_id() {
+        let first = fresh_id();
+        assert!(fresh_id() == first + 1, 1);
+        assert!(fresh_id() == first + 2, 2);
+    }
 }
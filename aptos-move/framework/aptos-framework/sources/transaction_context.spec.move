@@ -2,4 +2,8 @@ spec aptos_framework::transaction_context {
     spec get_script_hash { // TODO: temporary mockup.
         pragma opaque;
     }
+
+    spec fresh_id { // TODO: temporary mockup.
+        pragma opaque;
+    }
 }
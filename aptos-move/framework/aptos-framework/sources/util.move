@@ -1,10 +1,451 @@
 /// Utility functions used by the framework modules.
 module aptos_framework::util {
+    use std::option::Option;
+    use std::string::String;
+    #[test_only]
+    use std::string::utf8;
+
     friend aptos_framework::code;
     friend aptos_framework::gas_schedule;
 
+    /// The string is not a valid hex encoding.
+    const EINVALID_HEX: u64 = 2;
+
+    /// The string is not a valid base64 encoding.
+    const EINVALID_BASE64: u64 = 3;
+
+    /// The byte vector does not have the 8 bytes of a `u64`.
+    const EINVALID_U64_BYTES: u64 = 4;
+
+    /// The range `[start, start + len)` is not within the byte vector.
+    const EINVALID_SLICE_RANGE: u64 = 5;
+
+    /// The byte vectors do not have the same length.
+    const ELENGTH_MISMATCH: u64 = 6;
+
+    /// The vector could not be serialized.
+    const ESERIALIZATION_FAILURE: u64 = 7;
+
+    /// More than 65536 random bytes were requested.
+    const ETOO_MANY_RANDOM_BYTES: u64 = 8;
+
+    /// The denominator is 0.
+    const EDIVISION_BY_ZERO: u64 = 9;
+
+    /// The result does not fit in a `u128`.
+    const EMUL_DIV_OVERFLOW: u64 = 10;
+
     /// Native function to deserialize a type T.
     /// TODO: may want to move it in extra module if needed also in other places inside of the Fx.
     /// However, should not make this function public outside of the Fx.
     public(friend) native fun from_bytes<T: copy + drop>(bytes: vector<u8>): T;
+
+    /// Returns `true` if `a` and `b` are equal, comparing them in time that depends only on their lengths and not on
+    /// their contents. Use this instead of `==` when comparing secrets such as MAC tags or derived keys.
+    public native fun bytes_equal_constant_time(a: vector<u8>, b: vector<u8>): bool;
+
+    /// Returns `a` if `cond` is `true` and `b` otherwise, in time that does not depend on `cond` or on the contents of
+    /// `a` and `b`. Use this instead of `if` when the condition is secret.
+    /// Aborts with `ELENGTH_MISMATCH` if `a` and `b` have different lengths.
+    public native fun ct_select(cond: bool, a: vector<u8>, b: vector<u8>): vector<u8>;
+
+    /// Encodes `bytes` as a lowercase hex string, without a `0x` prefix.
+    public native fun to_hex(bytes: vector<u8>): String;
+
+    /// Decodes a hex string (lowercase or uppercase, without a `0x` prefix) into bytes.
+    /// Aborts with `EINVALID_HEX` if `s` has an odd length or contains a non-hex character.
+    public native fun from_hex(s: String): vector<u8>;
+
+    /// Encodes `bytes` as a padded base64 string, using the URL-safe alphabet (`-` and `_`) if `url_safe` is `true`
+    /// and the standard alphabet (`+` and `/`) otherwise.
+    public native fun base64_encode(bytes: vector<u8>, url_safe: bool): String;
+
+    /// Decodes a base64 string in the alphabet selected by `url_safe` (see `base64_encode`). The trailing `=` padding
+    /// is optional.
+    /// Aborts with `EINVALID_BASE64` if `s` contains characters outside of the alphabet or has an invalid length.
+    public native fun base64_decode(s: String, url_safe: bool): vector<u8>;
+
+    /// Returns `v` in reverse order, e.g., to convert a little-endian integer encoding into a big-endian one.
+    public native fun reverse_bytes(v: vector<u8>): vector<u8>;
+
+    /// Returns the 8-byte little-endian encoding of `n`. Same as `std::bcs::to_bytes(&n)`.
+    public native fun u64_to_le_bytes(n: u64): vector<u8>;
+
+    /// Returns the 8-byte big-endian encoding of `n`.
+    public native fun u64_to_be_bytes(n: u64): vector<u8>;
+
+    /// Decodes a `u64` from its 8-byte little-endian encoding.
+    /// Aborts with `EINVALID_U64_BYTES` if `bytes` is not exactly 8 bytes long.
+    public native fun u64_from_le_bytes(bytes: vector<u8>): u64;
+
+    /// Decodes a `u64` from its 8-byte big-endian encoding.
+    /// Aborts with `EINVALID_U64_BYTES` if `bytes` is not exactly 8 bytes long.
+    public native fun u64_from_be_bytes(bytes: vector<u8>): u64;
+
+    /// Returns the full 256-bit product `a * b` as its high and low 128-bit halves `(hi, lo)`, i.e.,
+    /// `a * b = hi * 2^128 + lo`. Never aborts, unlike `a * b` on overflow.
+    public native fun mul_u128_wide(a: u128, b: u128): (u128, u128);
+
+    /// Returns the inverse of `a` modulo `modulus`, i.e., the `x < modulus` such that `a * x = 1 (mod modulus)`, or
+    /// `None` if it does not exist because `a` and `modulus` are not coprime or `modulus` is 0.
+    public native fun mod_inverse(a: u128, modulus: u128): Option<u128>;
+
+    /// Returns `a * b / denominator`, rounded up if `round_up` is `true` and down otherwise. The product is computed
+    /// with 256 bits of precision (see `mul_u128_wide`), so only the final result has to fit in a `u128`.
+    /// Aborts with `EDIVISION_BY_ZERO` if `denominator` is 0, and with `EMUL_DIV_OVERFLOW` if the result does not fit
+    /// in a `u128`.
+    public native fun mul_div(a: u128, b: u128, denominator: u128, round_up: bool): u128;
+
+    /// Returns the `len` bytes of `v` starting at index `start`.
+    /// Aborts with `EINVALID_SLICE_RANGE` if `start + len` exceeds the length of `v`.
+    public native fun bytes_slice(v: vector<u8>, start: u64, len: u64): vector<u8>;
+
+    /// Returns the bytes of `a` followed by the bytes of `b`.
+    public native fun bytes_concat(a: vector<u8>, b: vector<u8>): vector<u8>;
+
+    /// Rotates the bits of `n` left by `shift` bits. The shift is taken modulo 64, so shifting by 64 returns `n`.
+    public native fun rotl_u64(n: u64, shift: u8): u64;
+
+    /// Rotates the bits of `n` right by `shift` bits. The shift is taken modulo 64, so shifting by 64 returns `n`.
+    public native fun rotr_u64(n: u64, shift: u8): u64;
+
+    /// Rotates the bits of `n` left by `shift` bits. The shift is taken modulo 128, so shifting by 128 returns `n`.
+    public native fun rotl_u128(n: u128, shift: u8): u128;
+
+    /// Rotates the bits of `n` right by `shift` bits. The shift is taken modulo 128, so shifting by 128 returns `n`.
+    public native fun rotr_u128(n: u128, shift: u8): u128;
+
+    /// Returns the number of bits of `n` that are set.
+    public native fun count_ones_u128(n: u128): u8;
+
+    /// Returns the number of zero bits of `n` above its highest set bit, or 128 if `n` is 0. For `n > 0`,
+    /// `127 - leading_zeros_u128(n)` is `floor(log2(n))`.
+    public native fun leading_zeros_u128(n: u128): u8;
+
+    /// Returns the number of zero bits of `n` below its lowest set bit, or 128 if `n` is 0.
+    public native fun trailing_zeros_u128(n: u128): u8;
+
+    /// Returns `v` shuffled with a permutation derived deterministically from `seed`, so anyone can reproduce it.
+    ///
+    /// The permutation is a Fisher-Yates shuffle driven by a ChaCha20 RNG (as in the Rust `rand_chacha` crate) seeded
+    /// with `sha3_256(seed)`. For `i` from `length(v) - 1` down to 1, `u64`s are drawn from the RNG until one, `r`, is
+    /// below the largest multiple of `i + 1` that fits in a `u64`, and the elements at `i` and `r % (i + 1)` are
+    /// swapped. The shuffle is only as unpredictable as `seed`.
+    public fun shuffle<T>(v: vector<T>, seed: vector<u8>): vector<T> {
+        shuffle_in_place(&mut v, seed);
+        v
+    }
+
+    native fun shuffle_in_place<T>(v: &mut vector<T>, seed: vector<u8>);
+
+    /// Returns `n` pseudo-random bytes derived deterministically from `seed`, so anyone can reproduce them: the first
+    /// `n` bytes of the output of a ChaCha20 RNG (as in the Rust `rand_chacha` crate) seeded with `sha3_256(seed)`, i.e.,
+    /// the ChaCha20 keystream with key `sha3_256(seed)` and an all-zero nonce. This is NOT secure randomness: the bytes
+    /// are only as unpredictable as `seed`.
+    /// Aborts with `ETOO_MANY_RANDOM_BYTES` if `n` is larger than 65536.
+    public native fun random_bytes(seed: vector<u8>, n: u64): vector<u8>;
+
+    /// Returns a sha3-256 commitment to `items`, which off-chain verifiers can reproduce as
+    /// `sha3_256(le_u64(n) || le_u64(len(b_1)) || b_1 || ... || le_u64(len(b_n)) || b_n)`, where `n` is the number of
+    /// items, `b_i` is the BCS serialization of the i-th item and `le_u64` is the 8-byte little-endian encoding.
+    /// Aborts with `ESERIALIZATION_FAILURE` if the items cannot be serialized.
+    public native fun commit_vec<T>(items: vector<T>): vector<u8>;
+
+    /// Returns a copy of `v` sorted in ascending order.
+    public native fun sort_u64(v: vector<u64>): vector<u64>;
+
+    /// Returns a copy of `v` sorted in ascending order.
+    public native fun sort_u128(v: vector<u128>): vector<u128>;
+
+    #[test]
+    fun test_bytes_equal_constant_time() {
+        assert!(bytes_equal_constant_time(x"", x""), 1);
+        assert!(bytes_equal_constant_time(x"deadbeef", x"deadbeef"), 2);
+        assert!(!bytes_equal_constant_time(x"deadbeef", x"deadbeee"), 3);
+        assert!(!bytes_equal_constant_time(x"deadbeef", x"deadbe"), 4);
+    }
+
+    #[test]
+    fun test_ct_select() {
+        assert!(ct_select(true, x"deadbeef", x"01020304") == x"deadbeef", 1);
+        assert!(ct_select(false, x"deadbeef", x"01020304") == x"01020304", 2);
+        assert!(ct_select(true, x"", x"") == x"", 3);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65542)]
+    fun test_ct_select_length_mismatch() {
+        ct_select(true, x"deadbeef", x"010203");
+    }
+
+    #[test]
+    fun test_hex() {
+        assert!(to_hex(x"") == utf8(b""), 1);
+        assert!(to_hex(x"00ff10deadbeef") == utf8(b"00ff10deadbeef"), 2);
+        assert!(from_hex(utf8(b"00ff10deadbeef")) == x"00ff10deadbeef", 3);
+        assert!(from_hex(utf8(b"DEADBEEF")) == x"deadbeef", 4);
+        assert!(from_hex(to_hex(x"0123456789abcdef")) == x"0123456789abcdef", 5);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65538)]
+    fun test_from_hex_odd_length() {
+        from_hex(utf8(b"abc"));
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65538)]
+    fun test_from_hex_invalid_character() {
+        from_hex(utf8(b"0x12"));
+    }
+
+    #[test]
+    fun test_base64() {
+        assert!(base64_encode(b"hello", false) == utf8(b"aGVsbG8="), 1);
+        assert!(base64_encode(x"fbfffe", false) == utf8(b"+//+"), 2);
+        assert!(base64_encode(x"fbfffe", true) == utf8(b"-__-"), 3);
+        assert!(base64_decode(utf8(b"aGVsbG8="), false) == b"hello", 4);
+        assert!(base64_decode(utf8(b"aGVsbG8"), false) == b"hello", 5);
+        assert!(base64_decode(utf8(b"-__-"), true) == x"fbfffe", 6);
+        assert!(base64_decode(utf8(b""), false) == x"", 7);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65539)]
+    fun test_base64_decode_wrong_alphabet() {
+        base64_decode(utf8(b"-__-"), false);
+    }
+
+    #[test]
+    fun test_endianness() {
+        assert!(reverse_bytes(x"") == x"", 1);
+        assert!(reverse_bytes(x"0102030405") == x"0504030201", 2);
+
+        let n = 0x0102030405060708;
+        assert!(u64_to_le_bytes(n) == x"0807060504030201", 3);
+        assert!(u64_to_le_bytes(n) == std::bcs::to_bytes(&n), 4);
+        assert!(u64_to_be_bytes(n) == x"0102030405060708", 5);
+        assert!(u64_to_be_bytes(n) == reverse_bytes(u64_to_le_bytes(n)), 6);
+
+        assert!(u64_from_le_bytes(u64_to_le_bytes(n)) == n, 7);
+        assert!(u64_from_be_bytes(u64_to_be_bytes(n)) == n, 8);
+        assert!(u64_from_le_bytes(x"ffffffffffffffff") == 18446744073709551615, 9);
+        assert!(u64_from_be_bytes(x"0000000000000001") == 1, 10);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65540)]
+    fun test_u64_from_le_bytes_wrong_length() {
+        u64_from_le_bytes(x"01020304");
+    }
+
+    #[test]
+    fun test_mul_u128_wide() {
+        let max = 340282366920938463463374607431768211455;
+
+        let (hi, lo) = mul_u128_wide(0, max);
+        assert!(hi == 0 && lo == 0, 1);
+        let (hi, lo) = mul_u128_wide(3, 5);
+        assert!(hi == 0 && lo == 15, 2);
+        // Largest product that still fits in 128 bits
+        let (hi, lo) = mul_u128_wide(max, 1);
+        assert!(hi == 0 && lo == max, 3);
+        // 2^64 * 2^64 = 2^128
+        let (hi, lo) = mul_u128_wide(18446744073709551616, 18446744073709551616);
+        assert!(hi == 1 && lo == 0, 4);
+        // max * 2 = 2^129 - 2
+        let (hi, lo) = mul_u128_wide(max, 2);
+        assert!(hi == 1 && lo == max - 1, 5);
+        // max * max = 2^256 - 2^129 + 1
+        let (hi, lo) = mul_u128_wide(max, max);
+        assert!(hi == max - 1 && lo == 1, 6);
+    }
+
+    #[test]
+    fun test_mod_inverse() {
+        use std::option;
+
+        assert!(mod_inverse(3, 7) == option::some(5), 1);
+        assert!(mod_inverse(10, 7) == option::some(5), 2);
+        assert!(mod_inverse(1, 2) == option::some(1), 3);
+        assert!(mod_inverse(5, 1) == option::some(0), 4);
+        // 2^128 - 159 is prime
+        let p = 340282366920938463463374607431768211297;
+        assert!(mod_inverse(2, p) == option::some(170141183460469231731687303715884105649), 5);
+        assert!(mod_inverse(340282366920938463463374607431768211455, p) == option::some(243366502924468647920008421770821568839), 6);
+        assert!(mod_inverse(p - 1, p) == option::some(p - 1), 7);
+    }
+
+    #[test]
+    fun test_mod_inverse_not_coprime() {
+        use std::option;
+
+        assert!(mod_inverse(6, 9) == option::none(), 1);
+        assert!(mod_inverse(0, 7) == option::none(), 2);
+        assert!(mod_inverse(7, 7) == option::none(), 3);
+        assert!(mod_inverse(3, 0) == option::none(), 4);
+    }
+
+    #[test]
+    fun test_mul_div() {
+        let max = 340282366920938463463374607431768211455;
+
+        assert!(mul_div(7, 3, 2, false) == 10, 1);
+        assert!(mul_div(7, 3, 2, true) == 11, 2);
+        assert!(mul_div(6, 3, 2, true) == 9, 3);
+        assert!(mul_div(0, 5, 7, true) == 0, 4);
+        // The product overflows a u128 but the result does not
+        assert!(mul_div(max, max, max, false) == max, 5);
+        assert!(mul_div(max, max - 1, max, true) == max - 1, 6);
+        assert!(mul_div(max, 2, 3, false) == 226854911280625642308916404954512140970, 7);
+        assert!(mul_div(123456789012345678901234567890, 987654321098765432109876543210, 1267650600228229401496703205376, false) == 96187885774730743333048541186, 8);
+        assert!(mul_div(123456789012345678901234567890, 987654321098765432109876543210, 1267650600228229401496703205376, true) == 96187885774730743333048541187, 9);
+        // 7 * 97223533405982418132392744980505203273 = 2 * max + 1
+        assert!(mul_div(7, 97223533405982418132392744980505203273, 2, false) == max, 10);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65545)]
+    fun test_mul_div_by_zero() {
+        mul_div(1, 2, 0, false);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 131082)]
+    fun test_mul_div_overflow() {
+        let max = 340282366920938463463374607431768211455;
+        mul_div(max, max, max - 1, false);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 131082)]
+    fun test_mul_div_round_up_overflow() {
+        mul_div(7, 97223533405982418132392744980505203273, 2, true);
+    }
+
+    #[test]
+    fun test_shuffle() {
+        let v = vector[0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+        assert!(shuffle(v, b"aptos") == vector[4, 6, 0, 9, 8, 3, 2, 1, 5, 7], 1);
+        assert!(shuffle(v, b"") == vector[4, 9, 5, 3, 8, 6, 0, 7, 2, 1], 2);
+        assert!(shuffle(v, b"aptos") == shuffle(v, b"aptos"), 3);
+        assert!(shuffle(vector[utf8(b"a"), utf8(b"b")], b"aptos") == vector[utf8(b"a"), utf8(b"b")], 4);
+        assert!(shuffle(vector<u8>[], b"aptos") == vector[], 5);
+        assert!(shuffle(vector[42], b"aptos") == vector[42], 6);
+    }
+
+    #[test]
+    fun test_random_bytes() {
+        assert!(random_bytes(b"aptos", 16) == x"f17dff81c68713140875814b15d3c79a", 1);
+        assert!(random_bytes(b"", 8) == x"e5e5fcbcab32d7fc", 2);
+        // Longer outputs extend shorter ones
+        assert!(
+            random_bytes(b"aptos", 40) ==
+                x"f17dff81c68713140875814b15d3c79a11693e370a0d4e5a4eff91e8510044442f4866c5c02b487c",
+            3
+        );
+        assert!(random_bytes(b"aptos", 0) == x"", 4);
+        assert!(std::vector::length(&random_bytes(b"aptos", 65536)) == 65536, 5);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65544)]
+    fun test_random_bytes_too_many() {
+        random_bytes(b"aptos", 65537);
+    }
+
+    #[test]
+    fun test_commit_vec() {
+        assert!(commit_vec(vector<u64>[]) == x"48dda5bbe9171a6656206ec56c595c5834b6cf38c5fe71bcb44fe43833aee9df", 1);
+        assert!(commit_vec(vector<u64>[1, 2]) == x"25277d731db3ce81b9abb88477a3ca2e40efd8520d1398a5622476ecde482937", 2);
+
+        let a = std::bcs::to_bytes(&utf8(b"a"));
+        let bc = std::bcs::to_bytes(&utf8(b"bc"));
+        let preimage = u64_to_le_bytes(2);
+        std::vector::append(&mut preimage, u64_to_le_bytes(std::vector::length(&a)));
+        std::vector::append(&mut preimage, a);
+        std::vector::append(&mut preimage, u64_to_le_bytes(std::vector::length(&bc)));
+        std::vector::append(&mut preimage, bc);
+        assert!(commit_vec(vector[utf8(b"a"), utf8(b"bc")]) == std::hash::sha3_256(preimage), 3);
+
+        // The commitment depends on the order of the items
+        assert!(commit_vec(vector[utf8(b"bc"), utf8(b"a")]) != commit_vec(vector[utf8(b"a"), utf8(b"bc")]), 4);
+    }
+
+    #[test]
+    fun test_sort() {
+        assert!(sort_u64(vector[3, 1, 2, 1, 18446744073709551615, 0]) == vector[0, 1, 1, 2, 3, 18446744073709551615], 1);
+        assert!(sort_u64(vector[]) == vector[], 2);
+        assert!(sort_u64(vector[7]) == vector[7], 3);
+        assert!(sort_u128(vector[340282366920938463463374607431768211455, 5, 18446744073709551616]) ==
+            vector[5, 18446744073709551616, 340282366920938463463374607431768211455], 4);
+    }
+
+    #[test]
+    fun test_bytes_slice_and_concat() {
+        let v = x"0102030405";
+        assert!(bytes_slice(v, 0, 5) == v, 1);
+        assert!(bytes_slice(v, 1, 3) == x"020304", 2);
+        assert!(bytes_slice(v, 5, 0) == x"", 3);
+        assert!(bytes_concat(x"0102", x"030405") == v, 4);
+        assert!(bytes_concat(x"", x"") == x"", 5);
+        assert!(bytes_concat(bytes_slice(v, 0, 2), bytes_slice(v, 2, 3)) == v, 6);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65541)]
+    fun test_bytes_slice_out_of_range() {
+        bytes_slice(x"0102030405", 3, 3);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65541)]
+    fun test_bytes_slice_overflow() {
+        bytes_slice(x"0102030405", 1, 18446744073709551615);
+    }
+
+    #[test]
+    fun test_rotate_u64() {
+        let n = 0x8000000000000001;
+        assert!(rotl_u64(n, 0) == n, 1);
+        assert!(rotr_u64(n, 0) == n, 2);
+        assert!(rotl_u64(n, 1) == 0x0000000000000003, 3);
+        assert!(rotr_u64(n, 1) == 0xc000000000000000, 4);
+        assert!(rotl_u64(n, 63) == 0xc000000000000000, 5);
+        assert!(rotr_u64(n, 63) == 0x0000000000000003, 6);
+        assert!(rotl_u64(n, 64) == n, 7);
+        assert!(rotr_u64(n, 64) == n, 8);
+        assert!(rotl_u64(0x0123456789abcdef, 8) == 0x23456789abcdef01, 9);
+        assert!(rotr_u64(rotl_u64(0x0123456789abcdef, 13), 13) == 0x0123456789abcdef, 10);
+    }
+
+    #[test]
+    fun test_rotate_u128() {
+        let n = 0x80000000000000000000000000000001;
+        assert!(rotl_u128(n, 0) == n, 1);
+        assert!(rotr_u128(n, 0) == n, 2);
+        assert!(rotl_u128(n, 1) == 0x00000000000000000000000000000003, 3);
+        assert!(rotr_u128(n, 1) == 0xc0000000000000000000000000000000, 4);
+        assert!(rotl_u128(n, 127) == 0xc0000000000000000000000000000000, 5);
+        assert!(rotr_u128(n, 127) == 0x00000000000000000000000000000003, 6);
+        assert!(rotl_u128(n, 128) == n, 7);
+        assert!(rotr_u128(n, 128) == n, 8);
+        assert!(rotr_u128(rotl_u128(n, 200), 200) == n, 9);
+    }
+
+    #[test]
+    fun test_bit_count_u128() {
+        let max = 340282366920938463463374607431768211455;
+        assert!(count_ones_u128(0) == 0, 1);
+        assert!(count_ones_u128(max) == 128, 2);
+        assert!(count_ones_u128(0xf0f0) == 8, 3);
+        assert!(leading_zeros_u128(0) == 128, 4);
+        assert!(leading_zeros_u128(max) == 0, 5);
+        assert!(leading_zeros_u128(1) == 127, 6);
+        assert!(leading_zeros_u128(0x10000000000000000) == 63, 7);
+        assert!(trailing_zeros_u128(0) == 128, 8);
+        assert!(trailing_zeros_u128(max) == 0, 9);
+        assert!(trailing_zeros_u128(0x80000000000000000000000000000000) == 127, 10);
+        assert!(trailing_zeros_u128(0xf0f0) == 4, 11);
+    }
 }
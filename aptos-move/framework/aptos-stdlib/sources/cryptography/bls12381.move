@@ -16,6 +16,12 @@ module aptos_std::bls12381 {
     /// The caller was supposed to input one or more public keys.
     const EZERO_PUBKEYS: u64 = 1;
 
+    /// The caller was supposed to input a non-empty domain separation tag (DST).
+    const EEMPTY_DST: u64 = 2;
+
+    /// The caller was supposed to input as many messages as public keys.
+    const ELENGTH_MISMATCH: u64 = 3;
+
     // TODO: Performance would increase if structs in this module are implemented natively via handles (similar to Table and
     // RistrettoPoint). This will avoid unnecessary (de)serialization. We would need to allow storage of these structs too.
 
@@ -184,6 +190,24 @@ module aptos_std::bls12381 {
         verify_aggregate_signature_internal(aggr_sig.bytes, public_keys, messages)
     }
 
+    /// Verifies an aggregate signature `aggregate_sig` on `messages` under the serialized `pubkeys`, where `messages[i]`
+    /// should be signed by `pubkeys[i]`, without requiring proofs-of-possession (PoPs) for the public keys. Instead, as
+    /// in the basic scheme of the IETF BLS draft standard, all messages must be distinct, which prevents rogue-key
+    /// attacks. Every public key is checked for prime-order subgroup membership.
+    /// Returns `false` if no public keys are given, if two messages are equal or if any input fails to deserialize.
+    /// Aborts if the number of messages does not equal the number of public keys.
+    public fun aggregate_verify_distinct(
+        pubkeys: vector<vector<u8>>,
+        messages: vector<vector<u8>>,
+        aggregate_sig: vector<u8>,
+    ): bool {
+        assert!(
+            std::vector::length(&pubkeys) == std::vector::length(&messages),
+            std::error::invalid_argument(ELENGTH_MISMATCH)
+        );
+        aggregate_verify_distinct_internal(pubkeys, messages, aggregate_sig)
+    }
+
     /// Verifies a multisignature: an aggregation of many signatures, each on the same message `m`.
     public fun verify_multisignature(
         multisig: &AggrOrMultiSignature,
@@ -193,6 +217,22 @@ module aptos_std::bls12381 {
         verify_multisignature_internal(multisig.bytes, aggr_public_key.bytes, message)
     }
 
+    /// Verifies a multisignature `aggregate_sig` on `message` under a serialized aggregate public key that was computed
+    /// earlier and cached, e.g., across epochs, to avoid re-aggregating the public keys on every verification.
+    /// Since the aggregate public key is not known to come from `aggregate_pubkeys`, it is checked for prime-order
+    /// subgroup membership. Returns `false` if either input does not deserialize or fails the subgroup check.
+    ///
+    /// A multisignature verifies like a normal signature under the aggregate public key, so this runs the same check as
+    /// `verify_normal_signature`, which always subgroup-checks the public key, and skips the cost of re-aggregating the
+    /// public keys with `aggregate_pubkeys`.
+    public fun verify_with_aggregate_pubkey(
+        aggregate_pubkey: vector<u8>,
+        message: vector<u8>,
+        aggregate_sig: vector<u8>,
+    ): bool {
+        verify_normal_signature_internal(aggregate_sig, aggregate_pubkey, message)
+    }
+
     /// Verifies a normal, non-aggregated signature.
     public fun verify_normal_signature(
         signature: &Signature,
@@ -211,6 +251,16 @@ module aptos_std::bls12381 {
         verify_signature_share_internal(signature_share.bytes, public_key.bytes, message)
     }
 
+    /// Hashes an arbitrary `message` to an element of the prime-order subgroup of $\mathbb{G}_2$ using the
+    /// `BLS12381G2_XMD:SHA-256_SSWU_RO_` suite of [RFC 9380](https://datatracker.ietf.org/doc/html/rfc9380), with `dst`
+    /// as the domain separation tag. This is the same hash-to-curve step used when verifying BLS signatures.
+    /// Returns the 96-byte compressed serialization of the point.
+    /// Aborts if `dst` is empty.
+    public fun hash_to_g2(message: vector<u8>, dst: vector<u8>): vector<u8> {
+        assert!(!std::vector::is_empty(&dst), std::error::invalid_argument(EEMPTY_DST));
+        hash_to_g2_internal(message, dst)
+    }
+
     //
     // Native functions
     //
@@ -234,6 +284,26 @@ module aptos_std::bls12381 {
     /// Does not abort.
     native fun aggregate_signatures_internal(signatures: vector<Signature>): (vector<u8>, bool);
 
+    /// Returns `true` if the aggregate signature `aggregate_sig` on `messages` under `pubkeys` verifies, where
+    /// `messages[i]` should be signed by `pubkeys[i]`. Subgroup-checks all public keys and the aggregate signature.
+    ///
+    /// Returns `false` if either:
+    /// - no public keys or messages are given as input,
+    /// - number of messages does not equal number of public keys,
+    /// - two messages are equal,
+    /// - a public key or `aggregate_sig` does not deserialize or is not a prime-order point.
+    /// Does not abort.
+    native fun aggregate_verify_distinct_internal(
+        pubkeys: vector<vector<u8>>,
+        messages: vector<vector<u8>>,
+        aggregate_sig: vector<u8>,
+    ): bool;
+
+    /// Hashes `message` to a prime-order point in $\mathbb{G}_2$ under the domain separation tag `dst` and returns its
+    /// 96-byte compressed serialization.
+    /// Does not abort.
+    native fun hash_to_g2_internal(message: vector<u8>, dst: vector<u8>): vector<u8>;
+
     /// Return `true` if the bytes in `public_key` are a valid BLS12-381 public key:
     ///  (1) it is NOT the identity point, and
     ///  (2) it is a BLS12-381 elliptic curve point, and
@@ -350,6 +420,24 @@ module aptos_std::bls12381 {
         }
     }
 
+    #[test]
+    fun test_hash_to_g2() {
+        // Test case generated by calling `blst_hash_to_g2` directly, with the DST used for Aptos BLS signatures
+        let point = hash_to_g2(b"abc", b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_");
+        assert!(point == x"94b38e10fd6d2d63dfe704c3f0b1741474dfeaef88d6cdca4334413320701c74e5df8c7859947f6901c0a3c30dba23c91400ddb63494b2f3717d8706a834f928323cef590dd1f2bc8edaf857889e82c9b4cf242324526c9045bc8fec05f98fe9", 1);
+        assert!(signature_subgroup_check_internal(point), 1);
+
+        // A different DST must yield a different point
+        let other = hash_to_g2(b"abc", b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_");
+        assert!(other != point, 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65538)]
+    fun test_hash_to_g2_empty_dst() {
+        hash_to_g2(b"abc", std::vector::empty());
+    }
+
     #[test]
     fun test_pubkey_validation() {
         // test low order points (in group for PK)
@@ -568,6 +656,62 @@ module aptos_std::bls12381 {
         };
     }
 
+    #[test]
+    fun test_verify_with_aggregate_pubkey() {
+        // Test case from `test_verify_multisig`: the sum of three public keys and a multisignature on
+        // "Hello, Aptoverse!" under it
+        let aggregate_pubkey = x"b5f5eb6153ab5388a1a76343d714e4a2dcf224c5d0722d1e8e90c6bcead05c573fffe986460bd4000645a655bf52bc60";
+        let aggregate_sig = x"b523a31813e771e55aa0fc99a48db716ecc1085f9899ccadb64e759ecb481a2fb1cdcc0b266f036695f941361de773081729311f6a1bca9d47393f5359c8c87dc34a91f5dae335590aacbff974076ad1f910dd81750553a72ccbcad3c8cc0f07";
+
+        assert!(verify_with_aggregate_pubkey(aggregate_pubkey, b"Hello, Aptoverse!", aggregate_sig), 1);
+        assert!(!verify_with_aggregate_pubkey(aggregate_pubkey, b"Hello, Aptoverse?", aggregate_sig), 2);
+        assert!(!verify_with_aggregate_pubkey(x"00", b"Hello, Aptoverse!", aggregate_sig), 3);
+        assert!(!verify_with_aggregate_pubkey(aggregate_pubkey, b"Hello, Aptoverse!", x"00"), 4);
+    }
+
+    #[test]
+    fun test_aggregate_verify_distinct() {
+        // Same test-cases as in `test_verify_aggsig`, whose messages "Hello, Aptos <i>!" are all distinct
+        let msgs = vector[
+        x"48656c6c6f2c204170746f73203121",
+        x"48656c6c6f2c204170746f73203221",
+        x"48656c6c6f2c204170746f73203321",
+        ];
+
+        let pks = vector[
+        x"b93d6aabb2b83e52f4b8bda43c24ea920bbced87a03ffc80f8f70c814a8b3f5d69fbb4e579ca76ee008d61365747dbc6",
+        x"b45648ceae3a983bcb816a96db599b5aef3b688c5753fa20ce36ac7a4f2c9ed792ab20af6604e85e42dab746398bb82c",
+        x"b3e4921277221e01ed71284be5e3045292b26c7f465a6fcdba53ee47edd39ec5160da3b229a73c75671024dcb36de091",
+        ];
+
+        let aggsig = x"abed10f464de74769121fc09715e59a3ac96a5054a43a9d43cc890a2d4d332614c74c7fb4cceef6d25f85c65dee337330f062f89f23fec9ecf7ce3193fbba2c886630d753be6a4513a4634428904b767af2f230c5cadbcb53a451dd9c7d977f6";
+
+        assert!(aggregate_verify_distinct(pks, msgs, aggsig), 1);
+
+        // Verifying the aggregate of the first two signatures under all three public keys fails
+        let aggsig2 = x"a523aa3c3f1f1074d968ffecf017c7b93ae5243006bf0abd2e45c036ddbec99302984b650ebe5ba306cda4071d281ba50a99ef0e66c3957fab94163296f9d673fc58a36de4276f82bfb1d9180b591df93b5c2804d40dd68cf0f72cd92f86442e";
+        assert!(!aggregate_verify_distinct(pks, msgs, aggsig2), 1);
+
+        // Repeated messages are rejected
+        let dup_msgs = vector[
+        x"48656c6c6f2c204170746f73203121",
+        x"48656c6c6f2c204170746f73203221",
+        x"48656c6c6f2c204170746f73203121",
+        ];
+        assert!(!aggregate_verify_distinct(pks, dup_msgs, aggsig), 1);
+
+        // No inputs and malformed public keys are rejected
+        assert!(!aggregate_verify_distinct(vector[], vector[], aggsig), 1);
+        *std::vector::borrow_mut(&mut pks, 0) = x"00";
+        assert!(!aggregate_verify_distinct(pks, msgs, aggsig), 1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65539)]
+    fun test_aggregate_verify_distinct_length_mismatch() {
+        aggregate_verify_distinct(vector[ x"ab" ], vector[], x"cd");
+    }
+
     #[test]
     /// Tests verification of a random BLS signature created using sk = x""
     fun test_verify_normal_and_verify_sigshare() {
@@ -0,0 +1,104 @@
+/// This module exposes arithmetic over the BN254 (a.k.a. alt_bn128) pairing-friendly elliptic curve, which can be
+/// used to implement custom pairing-based verifiers in Move (e.g., for SNARKs or KZG commitments).
+///
+/// Points and scalars are represented in the canonical (compressed) [arkworks](https://github.com/arkworks-rs)
+/// serialization:
+///  - a G1 point is 32 bytes,
+///  - a G2 point is 64 bytes,
+///  - a scalar is a 32-byte little-endian element of the scalar field.
+///
+/// All natives check that their input points are on the curve and in the prime-order subgroup, and abort otherwise.
+
+module aptos_std::bn254 {
+    /// One of the input points is malformed, not on the curve, or not in the prime-order subgroup
+    const EINVALID_POINT: u64 = 1;
+
+    /// The scalar is not a canonical element of the scalar field
+    const EINVALID_SCALAR: u64 = 2;
+
+    /// `pairing_check` was given a different number of G1 and G2 points
+    const ELENGTH_MISMATCH: u64 = 3;
+
+    /// Returns `p + q`, for G1 points `p` and `q`.
+    native public fun g1_add(p: vector<u8>, q: vector<u8>): vector<u8>;
+
+    /// Returns `scalar * p`, for a G1 point `p`.
+    native public fun g1_mul(p: vector<u8>, scalar: vector<u8>): vector<u8>;
+
+    /// Returns `p + q`, for G2 points `p` and `q`.
+    native public fun g2_add(p: vector<u8>, q: vector<u8>): vector<u8>;
+
+    /// Returns `scalar * p`, for a G2 point `p`.
+    native public fun g2_mul(p: vector<u8>, scalar: vector<u8>): vector<u8>;
+
+    /// Returns `true` if `e(g1s[0], g2s[0]) * ... * e(g1s[n-1], g2s[n-1])` is the identity in the target group.
+    /// Returns `true` for empty inputs.
+    native public fun pairing_check(g1s: vector<vector<u8>>, g2s: vector<vector<u8>>): bool;
+
+    //
+    // Constants for tests
+    //
+
+    const G1: vector<u8> = x"0100000000000000000000000000000000000000000000000000000000000000";
+    const G1_TIMES_2: vector<u8> = x"d3cf876dc108c2d3a81c8716a91678d9851518685b04859b021a132ee7440603";
+    const G1_TIMES_3: vector<u8> = x"f0ab15199655d3f279e6b81547d8159315bdb6b1bc3202f43fea6bc59abf6987";
+    const G1_NEG: vector<u8> = x"0100000000000000000000000000000000000000000000000000000000000080";
+    const G1_IDENTITY: vector<u8> = x"0000000000000000000000000000000000000000000000000000000000000040";
+
+    const G2: vector<u8> = x"edf692d95cbdde46ddda5ef7d422436779445c5e66006a42761e1f12efde0018c212f3aeb785e49712e7a9353349aaf1255dfb31b7bf60723a480d9293938e19";
+    const G2_TIMES_2: vector<u8> = x"b9b3b4620913f849ee2aa6a9cfd35c9d146f3e7c27596cc3e8d311fd3472dc2779ad28398ced57998435d8c63164b86d7033733ab82101b6379bf1b45d203ea0";
+    const G2_TIMES_3: vector<u8> = x"f5c7fb9406fade12005e9ab08c477e8d5e7192e12628e551900eb14d784e0606856eb6ff324f82c9a7a078686b1504bcebe4cf5dcd9151734297bb572f771410";
+
+    const SCALAR_2: vector<u8> = x"0200000000000000000000000000000000000000000000000000000000000000";
+    const SCALAR_3: vector<u8> = x"0300000000000000000000000000000000000000000000000000000000000000";
+
+    //
+    // Tests
+    //
+
+    #[test]
+    fun test_g1_arithmetic() {
+        assert!(g1_add(G1, G1_TIMES_2) == G1_TIMES_3, 1);
+        assert!(g1_mul(G1, SCALAR_3) == G1_TIMES_3, 2);
+        assert!(g1_add(G1, G1_NEG) == G1_IDENTITY, 3);
+    }
+
+    #[test]
+    fun test_g2_arithmetic() {
+        assert!(g2_add(G2, G2_TIMES_2) == G2_TIMES_3, 1);
+        assert!(g2_mul(G2, SCALAR_2) == G2_TIMES_2, 2);
+    }
+
+    #[test]
+    fun test_pairing_check() {
+        // e(2 * g1, g2) * e(-g1, 2 * g2) == 1
+        assert!(pairing_check(vector[G1_TIMES_2, G1_NEG], vector[G2, G2_TIMES_2]), 1);
+        // e(g1, g2) != 1
+        assert!(!pairing_check(vector[G1], vector[G2]), 2);
+        assert!(pairing_check(vector[], vector[]), 3);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65537)]
+    fun test_g1_add_invalid_point() {
+        g1_add(G1, x"ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65537)]
+    fun test_g2_add_wrong_size_point() {
+        g2_add(G2, G1);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65538)]
+    fun test_g1_mul_invalid_scalar() {
+        g1_mul(G1, x"ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff");
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65539)]
+    fun test_pairing_check_length_mismatch() {
+        pairing_check(vector[G1], vector[]);
+    }
+}
@@ -103,6 +103,11 @@ module aptos_std::ed25519 {
 
     /// Verifies a purported Ed25519 `signature` under an *unvalidated* `public_key` on the specified `message`.
     /// This call will validate the public key by checking it is NOT in the small subgroup.
+    ///
+    /// Aggregate signatures produced by MuSig-style multi-party signing (e.g., MuSig2 over Edwards25519) are ordinary
+    /// Ed25519 signatures under the aggregate public key, so they are verified by this function as well: the signers
+    /// aggregate their keys and nonces off-chain and produce `(R, s)` with `s * B = R + H(R || agg_pk || message) * agg_pk`,
+    /// where `H` is SHA-512 reduced modulo the group order, exactly as in RFC 8032.
     public fun signature_verify_strict(
         signature: &Signature,
         public_key: &UnvalidatedPublicKey,
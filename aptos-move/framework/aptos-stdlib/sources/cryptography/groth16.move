@@ -0,0 +1,69 @@
+/// This module implements verification of [Groth16](https://eprint.iacr.org/2016/260) zk-SNARK proofs over the BN254
+/// (a.k.a. alt_bn128) pairing-friendly elliptic curve.
+///
+/// All inputs are expected in the canonical (compressed) [arkworks](https://github.com/arkworks-rs) serialization:
+///  - the verifying key as an `ark_groth16::VerifyingKey<Bn254>`,
+///  - the proof as an `ark_groth16::Proof<Bn254>`,
+///  - each public input as a 32-byte little-endian `ark_bn254::Fr` scalar field element.
+
+module aptos_std::groth16 {
+    /// Returns `true` if `proof` is a valid Groth16 proof for the statement given by `public_inputs` under the
+    /// verifying key `vk`.
+    ///
+    /// Returns `false` if the proof does not verify, or if any of the inputs fails to deserialize: e.g., if a curve
+    /// point is not in the prime-order subgroup, if a public input is not a canonical field element, or if the number
+    /// of public inputs does not match the verifying key.
+    /// Does not abort.
+    public fun verify_proof(vk: vector<u8>, public_inputs: vector<vector<u8>>, proof: vector<u8>): bool {
+        verify_proof_internal(vk, public_inputs, proof)
+    }
+
+    //
+    // Native functions
+    //
+
+    native fun verify_proof_internal(vk: vector<u8>, public_inputs: vector<vector<u8>>, proof: vector<u8>): bool;
+
+    //
+    // Constants and helpers for tests
+    //
+
+    /// Verifying key for the circuit `a * b = c` with secret `a`, `b` and public `c`, generated with `ark-groth16`.
+    const TEST_VK: vector<u8> = x"e57314eb0d2d4acef7a0b56306a4ac1dc99b9a1dc15a34dc549a052171bd981b1e6533af92c383be56b39f0520f0c3f5713f404cc505f4887a88224fbf49562da2f948129e080d367595fcc2f8a6beee2c088f4e77fdfdb9edaaeb4b407d381617e42ded924236cbb7a82ba74b9ae3198aeef633e290a9931ed396a6e6109d1abb8615f6e3dfff68ed2be32ce4035b12e85792795c2f87bc0d2999cf9d2a81224795e7b72c7be59554ce1157ac2db49d2757e81bae97505a0d81ccbe88beb12f3e2e2c16d42cc821a62663a6cd59dcfa621b9aa7472df54a6f13500c1dea611e0200000000000000b26f91c2ead103aab2371f124fd6dfd68298e8c5ce92490d4915ca1125c70d03f2d3f67197a248fec62d27dbfdb388da398c1eefba02a8f3c7e695e68acbb209";
+
+    /// Proof that 33 = 3 * 11 for the circuit of `TEST_VK`.
+    const TEST_PROOF: vector<u8> = x"25f6939b573b6a0e3425af54d908a9fd4af3a74b6124bbbccc5394f21db867098b990563929f78ea9e6c4b0699b93ad23943062b3ebe8c2a46bbd8738e428225a5664b0b63724c8b4b85467eed74c0d754e66f73783ad65b618dedd84024a59513b60d791d4c58176fd3c643b38a45831f55a1213d95e0547d861fe60211ba83";
+
+    //
+    // Tests
+    //
+
+    #[test]
+    fun test_verify_proof() {
+        // c = 33
+        assert!(verify_proof(TEST_VK, vector[x"2100000000000000000000000000000000000000000000000000000000000000"], TEST_PROOF), 1);
+    }
+
+    #[test]
+    fun test_verify_proof_fails() {
+        // Wrong public input: c = 34
+        assert!(!verify_proof(TEST_VK, vector[x"2200000000000000000000000000000000000000000000000000000000000000"], TEST_PROOF), 1);
+
+        // Wrong number of public inputs
+        assert!(!verify_proof(TEST_VK, vector[], TEST_PROOF), 2);
+
+        // Non-canonical public input (larger than the BN254 scalar field modulus)
+        assert!(!verify_proof(TEST_VK, vector[x"ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"], TEST_PROOF), 3);
+
+        // Malformed proof
+        assert!(!verify_proof(TEST_VK, vector[x"2100000000000000000000000000000000000000000000000000000000000000"], x"25f6939b"), 4);
+
+        // Malformed verifying key
+        assert!(!verify_proof(x"", vector[x"2100000000000000000000000000000000000000000000000000000000000000"], TEST_PROOF), 5);
+
+        // Verifying key with trailing bytes, sized for more points than there are public inputs
+        let vk = TEST_VK;
+        std::vector::append(&mut vk, x"0000000000000000000000000000000000000000000000000000000000000000");
+        assert!(!verify_proof(vk, vector[x"2100000000000000000000000000000000000000000000000000000000000000"], TEST_PROOF), 6);
+    }
+}
@@ -68,6 +68,20 @@ module aptos_std::secp256k1 {
     // Native functions
     //
 
+    /// Returns `true` if the Ethereum address of the public key recovered from `signature` on `message_hash` is
+    /// `expected_address`, i.e., performs Ethereum's `ecrecover(message_hash, signature) == expected_address` check.
+    ///
+    /// `message_hash` is the 32-byte digest that was signed (e.g., the Keccak-256 hash of the message), `signature` is
+    /// the 65-byte Ethereum signature `r || s || v`, where the recovery ID `v` is either 0/1 or 27/28, and the
+    /// Ethereum address of a public key is the last 20 bytes of the Keccak-256 hash of its 64-byte raw encoding.
+    /// Aborts with `E_DESERIALIZE` if `message_hash` or `signature` are malformed, including if `v` is not one of 0, 1,
+    /// 27 or 28.
+    native public fun ecdsa_verify_eth_address(
+        message_hash: vector<u8>,
+        signature: vector<u8>,
+        expected_address: vector<u8>
+    ): bool;
+
     /// Returns `(public_key, true)` if `signature` verifies on `message` under the recovered `public_key`
     /// and returns `([], false)` otherwise.
     native fun ecdsa_recover_internal(
@@ -111,4 +125,63 @@ module aptos_std::secp256k1 {
         );
         assert!(std::option::is_none(&pk), 1);
     }
+
+    #[test]
+    /// Uses the same signature as `test_ecdsa_recover`; the Ethereum address of its signer is
+    /// 0xfcad0b19bb29d4674531d6f115237e16afce377c
+    fun test_ecdsa_verify_eth_address() {
+        use std::hash;
+
+        let message_hash = hash::sha2_256(b"test aptos secp256k1");
+        let address = x"fcad0b19bb29d4674531d6f115237e16afce377c";
+        let signature = x"f7ad936da03f948c14c542020e3c5f4e02aaacd1f20427c11aa6e2fbf8776477646bba0e1a37f9e7c777c423a1d2849baafd7ff6a9930814a43c3f80d59db56f";
+
+        let sig_with_v = signature;
+        std::vector::push_back(&mut sig_with_v, 0);
+        assert!(ecdsa_verify_eth_address(message_hash, sig_with_v, address), 1);
+
+        let sig_with_v = signature;
+        std::vector::push_back(&mut sig_with_v, 27);
+        assert!(ecdsa_verify_eth_address(message_hash, sig_with_v, address), 2);
+
+        // Wrong address
+        assert!(!ecdsa_verify_eth_address(message_hash, sig_with_v, x"0000000000000000000000000000000000000000"), 3);
+
+        // Wrong recovery ID, which recovers a different public key
+        let sig_with_v = signature;
+        std::vector::push_back(&mut sig_with_v, 28);
+        assert!(!ecdsa_verify_eth_address(message_hash, sig_with_v, address), 4);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65537)]
+    fun test_ecdsa_verify_eth_address_no_recovery_id() {
+        ecdsa_verify_eth_address(
+            std::hash::sha2_256(b"test aptos secp256k1"),
+            x"f7ad936da03f948c14c542020e3c5f4e02aaacd1f20427c11aa6e2fbf8776477646bba0e1a37f9e7c777c423a1d2849baafd7ff6a9930814a43c3f80d59db56f",
+            x"fcad0b19bb29d4674531d6f115237e16afce377c"
+        );
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65537)]
+    /// v = 29 would be recovery ID 2 if 27 were subtracted from any v >= 27, but Ethereum only accepts 27 and 28.
+    fun test_ecdsa_verify_eth_address_v_29() {
+        ecdsa_verify_eth_address(
+            std::hash::sha2_256(b"test aptos secp256k1"),
+            x"f7ad936da03f948c14c542020e3c5f4e02aaacd1f20427c11aa6e2fbf8776477646bba0e1a37f9e7c777c423a1d2849baafd7ff6a9930814a43c3f80d59db56f1d",
+            x"fcad0b19bb29d4674531d6f115237e16afce377c"
+        );
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65537)]
+    /// v = 2 is a recovery ID that libsecp256k1 accepts, but not a valid Ethereum encoding.
+    fun test_ecdsa_verify_eth_address_v_2() {
+        ecdsa_verify_eth_address(
+            std::hash::sha2_256(b"test aptos secp256k1"),
+            x"f7ad936da03f948c14c542020e3c5f4e02aaacd1f20427c11aa6e2fbf8776477646bba0e1a37f9e7c777c423a1d2849baafd7ff6a9930814a43c3f80d59db56f02",
+            x"fcad0b19bb29d4674531d6f115237e16afce377c"
+        );
+    }
 }
@@ -1,6 +1,21 @@
-/// Non-cryptographic hashes
+/// Cryptographic and non-cryptographic hashes
 module aptos_std::aptos_hash {
     use std::bcs;
+    #[test_only]
+    use std::hash::{sha2_256, sha3_256};
+
+    /// Selects SHA3-256 as the hash function in `hash_value`.
+    const SHA3_256: u8 = 0;
+    /// Selects Keccak-256 (as used by Ethereum) as the hash function in `hash_value`.
+    const KECCAK_256: u8 = 1;
+    /// Selects SHA2-256 as the hash function in `hash_value`.
+    const SHA2_256: u8 = 2;
+
+    /// An unknown hash algorithm was requested.
+    const EUNKNOWN_ALGORITHM: u64 = 1;   // This code must be the same, if ever returned from the native Rust implementation.
+
+    /// A hash is not 32 bytes long.
+    const EINVALID_HASH_LENGTH: u64 = 3;   // This code must be the same, if ever returned from the native Rust implementation.
 
     native public fun sip_hash(bytes: vector<u8>): u64;
 
@@ -10,8 +25,182 @@ module aptos_std::aptos_hash {
         sip_hash(bytes)
     }
 
+    /// Returns the double SHA2-256 hash `std::hash::sha2_256(std::hash::sha2_256(bytes))` of `bytes`, as used by
+    /// Bitcoin for block and transaction hashes. Note that Bitcoin tools display these hashes byte-reversed.
+    native public fun sha256d(bytes: vector<u8>): vector<u8>;
+
+    /// Returns the 4-byte Ethereum function selector of a Solidity function `signature` such as
+    /// `b"transfer(address,uint256)"`, i.e., the first 4 bytes of its Keccak-256 hash, as used to build EVM calldata.
+    native public fun eth_selector(signature: vector<u8>): vector<u8>;
+
+    /// Returns the CRC-32 checksum of `bytes`, as used by zlib, gzip and PNG. This is not a cryptographic hash.
+    native public fun crc32(bytes: vector<u8>): u64;
+
+    /// Returns the CRC-32C (Castagnoli) checksum of `bytes`, as used by iSCSI, ext4 and gRPC. This is not a
+    /// cryptographic hash.
+    native public fun crc32c(bytes: vector<u8>): u64;
+
+    /// BCS-serializes `v` and hashes the result in a single call, which is equivalent to hashing `bcs::to_bytes(v)`.
+    /// `algorithm` selects the hash function: 0 for SHA3-256, 1 for Keccak-256 and 2 for SHA2-256.
+    /// Aborts with `EUNKNOWN_ALGORITHM` if `algorithm` is none of these.
+    native public fun hash_value<MoveValue>(v: &MoveValue, algorithm: u8): vector<u8>;
+
+    /// Returns `true` if `proof` proves that `leaf` is in the Merkle tree with root `root`.
+    ///
+    /// The proof lists the sibling of each node on the path from the leaf up to (but excluding) the root. Starting
+    /// from `leaf`, each level hashes the current node and its sibling, concatenated in lexicographic order (the
+    /// smaller one first), with the hash function selected by `algorithm` (see `hash_value`). With Keccak-256, this is
+    /// the convention of OpenZeppelin's `MerkleProof`. `leaf` is used as is: hash the data into a leaf first.
+    /// Aborts with `EUNKNOWN_ALGORITHM` if `algorithm` is unknown.
+    native public fun verify_merkle_proof(
+        leaf: vector<u8>,
+        proof: vector<vector<u8>>,
+        root: vector<u8>,
+        algorithm: u8
+    ): bool;
+
+    /// Returns the hash of the Merkle tree node with children `a` and `b`, concatenated in lexicographic order (the
+    /// smaller one first) and hashed with the function selected by `algorithm` (see `hash_value`). This is one level of
+    /// `verify_merkle_proof`, e.g., to build the tree whose proofs it verifies; the order of `a` and `b` does not matter.
+    /// Aborts with `EUNKNOWN_ALGORITHM` if `algorithm` is unknown.
+    native public fun merkle_node_sorted(a: vector<u8>, b: vector<u8>, algorithm: u8): vector<u8>;
+
+    /// Returns the leaf hash of the Aptos transaction accumulator for the BCS-serialized `TransactionInfo`
+    /// `transaction_info_bytes`, exactly as the node computes it: `sha3_256(sha3_256(b"APTOS::TransactionInfo") | bytes)`.
+    native public fun accumulator_leaf_hash(transaction_info_bytes: vector<u8>): vector<u8>;
+
+    /// Returns the hash of the internal node of the Aptos transaction accumulator with the 32-byte children `left` and
+    /// `right`, exactly as the node computes it: `sha3_256(sha3_256(b"APTOS::TransactionAccumulator") | left | right)`.
+    /// Missing subtrees are represented by the placeholder hash `b"ACCUMULATOR_PLACEHOLDER_HASH"` padded with zeros to
+    /// 32 bytes.
+    /// Aborts with `EINVALID_HASH_LENGTH` if `left` or `right` is not 32 bytes long.
+    native public fun accumulator_internal_hash(left: vector<u8>, right: vector<u8>): vector<u8>;
+
     spec sip_hash_from_value {
         // TODO: temporary mockup.
         pragma opaque;
     }
+
+    //
+    // Tests
+    //
+
+    #[test]
+    fun sha256d_test() {
+        assert!(sha256d(b"") == x"5df6e0e2761359d30a8275058e299fcc0381534545f55cf43e41983f5d4c9456", 1);
+        assert!(sha256d(b"abc") == sha2_256(sha2_256(b"abc")), 2);
+        // The Bitcoin genesis block header, whose hash is displayed as
+        // 000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f
+        let header = x"0100000000000000000000000000000000000000000000000000000000000000000000003ba3edfd7a7b12b27ac72c3e67768f617fc81bc3888a51323a9fb8aa4b1e5e4a29ab5f49ffff001d1dac2b7c";
+        assert!(sha256d(header) == x"6fe28c0ab6f1b372c1a6a246ae63f74f931e8365e15a089c68d6190000000000", 3);
+    }
+
+    #[test]
+    fun eth_selector_test() {
+        // The selectors of the ERC-20 transfer, balanceOf and approve functions
+        assert!(eth_selector(b"transfer(address,uint256)") == x"a9059cbb", 1);
+        assert!(eth_selector(b"balanceOf(address)") == x"70a08231", 1);
+        assert!(eth_selector(b"approve(address,uint256)") == x"095ea7b3", 1);
+    }
+
+    #[test]
+    fun crc32_test() {
+        // The standard check values, i.e., the checksums of b"123456789"
+        assert!(crc32(b"") == 0, 1);
+        assert!(crc32(b"123456789") == 0xcbf43926, 1);
+        assert!(crc32(b"The quick brown fox jumps over the lazy dog") == 0x414fa339, 1);
+        assert!(crc32c(b"") == 0, 1);
+        assert!(crc32c(b"123456789") == 0xe3069283, 1);
+        assert!(crc32c(b"The quick brown fox jumps over the lazy dog") == 0x22620404, 1);
+    }
+
+    #[test]
+    fun hash_value_test() {
+        let v: u64 = 42;
+        assert!(hash_value(&v, SHA3_256) == sha3_256(bcs::to_bytes(&v)), 1);
+        assert!(hash_value(&v, SHA2_256) == sha2_256(bcs::to_bytes(&v)), 1);
+        assert!(hash_value(&v, KECCAK_256) == x"ee55bf17be166383be3ca3ff9d91bc5f3400bb658843fe52e62f5ceb16b5f101", 1);
+    }
+
+    #[test]
+    fun merkle_node_sorted_test() {
+        // Same tree as in `verify_merkle_proof_test`
+        let root = x"f09a5a6a9191919fc829337a0bc61ef8ab2e6cdef2baece3499da9e549d01fc7";
+        let node_ab = merkle_node_sorted(sha3_256(b"a"), sha3_256(b"b"), SHA3_256);
+        let node_cd = merkle_node_sorted(sha3_256(b"d"), sha3_256(b"c"), SHA3_256);
+
+        assert!(node_ab == x"29df505440ebe180c00857e92b0694c56a33762b08944472492b0cbf6ec607e3", 1);
+        assert!(node_cd == x"19a84217e939015aaa26d5da6b9ca673eae0df32877593df597cd3e5157982b1", 2);
+        assert!(node_ab == merkle_node_sorted(sha3_256(b"b"), sha3_256(b"a"), SHA3_256), 3);
+        assert!(merkle_node_sorted(node_ab, node_cd, SHA3_256) == root, 4);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65537)]
+    fun merkle_node_sorted_unknown_algorithm_test() {
+        merkle_node_sorted(x"01", x"02", 3);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65537)]
+    fun hash_value_unknown_algorithm_test() {
+        hash_value(&42, 3);
+    }
+
+    #[test]
+    fun verify_merkle_proof_test() {
+        // Tree over the leaves sha3_256(b"a"), sha3_256(b"b"), sha3_256(b"c") and sha3_256(b"d")
+        let root = x"f09a5a6a9191919fc829337a0bc61ef8ab2e6cdef2baece3499da9e549d01fc7";
+        let leaf_a = sha3_256(b"a");
+        let leaf_b = sha3_256(b"b");
+        let leaf_c = sha3_256(b"c");
+        let node_ab = x"29df505440ebe180c00857e92b0694c56a33762b08944472492b0cbf6ec607e3";
+        let node_cd = x"19a84217e939015aaa26d5da6b9ca673eae0df32877593df597cd3e5157982b1";
+
+        assert!(verify_merkle_proof(leaf_a, vector[leaf_b, node_cd], root, SHA3_256), 1);
+        assert!(verify_merkle_proof(leaf_b, vector[leaf_a, node_cd], root, SHA3_256), 2);
+        assert!(verify_merkle_proof(leaf_c, vector[sha3_256(b"d"), node_ab], root, SHA3_256), 3);
+        // An empty proof is valid only for the root itself
+        assert!(verify_merkle_proof(root, vector[], root, SHA3_256), 4);
+
+        assert!(!verify_merkle_proof(leaf_c, vector[leaf_b, node_cd], root, SHA3_256), 5);
+        assert!(!verify_merkle_proof(leaf_a, vector[node_cd, leaf_b], root, SHA3_256), 6);
+        assert!(!verify_merkle_proof(leaf_a, vector[leaf_b, node_cd], root, SHA2_256), 7);
+
+        // Tree over the leaves sha2_256(b"a"), sha2_256(b"b") and sha2_256(b"c"), where the last one is promoted
+        let root = x"aea2dd4249dcecf97ca6a1556db7f21ebd6a40bbec0243ca61b717146a08c347";
+        assert!(verify_merkle_proof(sha2_256(b"b"), vector[sha2_256(b"a"), sha2_256(b"c")], root, SHA2_256), 8);
+        assert!(verify_merkle_proof(
+            sha2_256(b"c"),
+            vector[x"18d79cb747ea174c59f3a3b41768672526d56fecc58360a99d283d0f9b0a3cc0"],
+            root,
+            SHA2_256
+        ), 9);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65537)]
+    fun verify_merkle_proof_unknown_algorithm_test() {
+        verify_merkle_proof(x"", vector[], x"", 3);
+    }
+
+    #[test]
+    fun accumulator_hash_test() {
+        assert!(accumulator_leaf_hash(b"aptos") == x"92ea748b07b56d5e871f1059dacd9f36577efdff1c20e3b2304d09b1a55d8f32", 1);
+
+        let leaf_a = accumulator_leaf_hash(b"a");
+        let leaf_b = accumulator_leaf_hash(b"b");
+        assert!(leaf_a == x"f3ad659c54ccb75729fa451bb6e760554cd2b7622a59827b8165b894b9befdaf", 2);
+        assert!(leaf_b == x"26b103fe419d883de2a05150689da70fe141942f0ec6c12658236e138a3c849b", 3);
+        assert!(accumulator_internal_hash(leaf_a, leaf_b) == x"852a3cdf7e8b49ffb1a81eca91a90c6b0fa408a8147194ef58ffb3ee6b271870", 4);
+
+        let placeholder = x"414343554d554c41544f525f504c414345484f4c4445525f4841534800000000";
+        assert!(accumulator_internal_hash(leaf_a, placeholder) == x"4f66dff9e54b9c94a3dfe71c1899050f0c3f31960ccf361e909b1452bae27a61", 5);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 65539)]
+    fun accumulator_internal_hash_invalid_length_test() {
+        accumulator_internal_hash(x"00", accumulator_leaf_hash(b"a"));
+    }
 }
@@ -22,10 +22,19 @@ module aptos_std::type_info {
     public native fun type_of<T>(): TypeInfo;
     public native fun type_name<T>(): string::String;
 
+    /// Parses a type string in the format returned by `type_name`, e.g., `vector<0x1::type_info::TypeInfo>`, and
+    /// returns the BCS bytes of the corresponding `TypeTag`. Aborts if `s` is not a valid type string, or if its type
+    /// arguments are nested more than 8 levels deep.
+    public native fun parse_type_tag(s: string::String): vector<u8>;
+
     spec type_of { // TODO: temporary mockup.
         pragma opaque;
     }
 
+    spec parse_type_tag { // TODO: temporary mockup.
+        pragma opaque;
+    }
+
     #[test]
     fun test() {
         let type_info = type_of<TypeInfo>();
@@ -60,4 +69,45 @@ module aptos_std::type_info {
             >
         >() == string::utf8(b"0x1::table::Table<0x1::type_info::TypeInfo, 0x1::table::Table<u8, vector<0x1::type_info::TypeInfo>>>"), 10);
     }
+
+    #[test]
+    fun test_parse_type_tag() {
+        // The BCS bytes of a TypeTag start with the index of its variant, e.g., 1 for u8 and 6 for vector
+        assert!(parse_type_tag(string::utf8(b"u8")) == x"01", 0);
+        assert!(parse_type_tag(string::utf8(b"vector<u64>")) == x"0602", 1);
+        assert!(parse_type_tag(type_name<vector<vector<bool>>>()) == x"060600", 2);
+        assert!(parse_type_tag(type_name<TypeInfo>()) == x"07000000000000000000000000000000000000000000000000000000000000000109747970655f696e666f0854797065496e666f00", 3);
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1)]
+    fun test_parse_type_tag_malformed() {
+        parse_type_tag(string::utf8(b"vector<u8"));
+    }
+
+    #[test]
+    fun test_parse_type_tag_max_nesting() {
+        assert!(
+            parse_type_tag(string::utf8(b"vector<vector<vector<vector<vector<vector<vector<vector<u8>>>>>>>>")) ==
+                x"060606060606060601",
+            0
+        );
+    }
+
+    #[test]
+    #[expected_failure(abort_code = 1)]
+    fun test_parse_type_tag_too_deeply_nested() {
+        let s = string::utf8(b"");
+        let i = 0;
+        while (i < 1000) {
+            string::append_utf8(&mut s, b"vector<");
+            i = i + 1;
+        };
+        string::append_utf8(&mut s, b"u8");
+        while (i > 0) {
+            string::append_utf8(&mut s, b">");
+            i = i - 1;
+        };
+        parse_type_tag(s);
+    }
 }
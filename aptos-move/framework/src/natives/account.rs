@@ -1,18 +1,33 @@
 // Copyright (c) Aptos
 // SPDX-License-Identifier: Apache-2.0
 
+use aptos_crypto::{ed25519::Ed25519PublicKey, multi_ed25519::MultiEd25519PublicKey};
+use aptos_types::{
+    account_address::create_object_address, transaction::authenticator::AuthenticationKey,
+};
 use move_deps::{
     move_binary_format::errors::PartialVMResult,
-    move_core_types::{account_address::AccountAddress, gas_algebra::InternalGas},
+    move_core_types::{
+        account_address::AccountAddress,
+        gas_algebra::{InternalGas, InternalGasPerArg, InternalGasPerByte, NumArgs, NumBytes},
+    },
     move_vm_runtime::native_functions::{NativeContext, NativeFunction},
     move_vm_types::{
-        loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+        loaded_data::runtime_types::Type,
+        natives::function::NativeResult,
+        pop_arg,
+        values::{Value, Vector},
     },
 };
 use smallvec::smallvec;
 use std::collections::VecDeque;
+use std::convert::TryFrom;
 use std::sync::Arc;
 
+/// Abort code when a public key is malformed (0x01 == INVALID_ARGUMENT)
+/// NOTE: This must match the code in the Move implementation
+const EMALFORMED_PUBLIC_KEY: u64 = 0x01_000B;
+
 /***************************************************************************************************
  * native fun create_address
  *
@@ -53,6 +68,47 @@ pub fn make_native_create_address(gas_params: CreateAddressGasParameters) -> Nat
     })
 }
 
+/***************************************************************************************************
+ * native fun create_object_address
+ *
+ *   gas cost: base_cost + per_byte_cost * seed_length
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct CreateObjectAddressGasParameters {
+    pub base_cost: InternalGas,
+    pub per_byte_cost: InternalGasPerByte,
+}
+
+/// Derives the address of an object, i.e., `sha3_256(creator | seed | 0xFE)`.
+fn native_create_object_address(
+    gas_params: &CreateObjectAddressGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(arguments.len() == 2);
+
+    let seed = pop_arg!(arguments, Vec<u8>);
+    let creator = pop_arg!(arguments, AccountAddress);
+
+    let cost = gas_params.base_cost + gas_params.per_byte_cost * NumBytes::new(seed.len() as u64);
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::address(create_object_address(creator, &seed))],
+    ))
+}
+
+pub fn make_native_create_object_address(
+    gas_params: CreateObjectAddressGasParameters,
+) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        native_create_object_address(&gas_params, context, ty_args, args)
+    })
+}
+
 /***************************************************************************************************
  * native fun create_signer
  *
@@ -86,6 +142,122 @@ pub fn make_native_create_signer(gas_params: CreateSignerGasParameters) -> Nativ
     })
 }
 
+/***************************************************************************************************
+ * native fun create_signers
+ *
+ *   gas cost: base_cost + per_signer_cost * num_addresses
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct CreateSignersGasParameters {
+    pub base_cost: InternalGas,
+    pub per_signer_cost: InternalGasPerArg,
+}
+
+fn native_create_signers(
+    gas_params: &CreateSignersGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let addresses = pop_arg!(arguments, Vec<AccountAddress>);
+
+    let cost =
+        gas_params.base_cost + gas_params.per_signer_cost * NumArgs::new(addresses.len() as u64);
+
+    let signers = addresses.into_iter().map(Value::signer).collect();
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Vector::pack(&Type::Signer, signers)?],
+    ))
+}
+
+pub fn make_native_create_signers(gas_params: CreateSignersGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        native_create_signers(&gas_params, context, ty_args, args)
+    })
+}
+
+/***************************************************************************************************
+ * native fun auth_key_from_ed25519_pubkey, auth_key_from_multi_ed25519_pubkey
+ *
+ *   gas cost: base_cost + per_byte_cost * pubkey_length
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct AuthKeyFromPubkeyGasParameters {
+    pub base_cost: InternalGas,
+    pub per_byte_cost: InternalGasPerByte,
+}
+
+/// Derives the authentication key of an Ed25519 public key, i.e., `sha3_256(pubkey | 0x00)`.
+fn native_auth_key_from_ed25519_pubkey(
+    gas_params: &AuthKeyFromPubkeyGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let pubkey = pop_arg!(arguments, Vec<u8>);
+
+    let cost = gas_params.base_cost + gas_params.per_byte_cost * NumBytes::new(pubkey.len() as u64);
+
+    match Ed25519PublicKey::try_from(pubkey.as_slice()) {
+        Ok(pk) => Ok(NativeResult::ok(
+            cost,
+            smallvec![Value::vector_u8(AuthenticationKey::ed25519(&pk).to_vec())],
+        )),
+        Err(_) => Ok(NativeResult::err(cost, EMALFORMED_PUBLIC_KEY)),
+    }
+}
+
+/// Derives the authentication key of a MultiEd25519 public key, i.e., `sha3_256(pubkey | 0x01)`.
+fn native_auth_key_from_multi_ed25519_pubkey(
+    gas_params: &AuthKeyFromPubkeyGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let pubkey = pop_arg!(arguments, Vec<u8>);
+
+    let cost = gas_params.base_cost + gas_params.per_byte_cost * NumBytes::new(pubkey.len() as u64);
+
+    match MultiEd25519PublicKey::try_from(pubkey.as_slice()) {
+        Ok(pk) => Ok(NativeResult::ok(
+            cost,
+            smallvec![Value::vector_u8(
+                AuthenticationKey::multi_ed25519(&pk).to_vec()
+            )],
+        )),
+        Err(_) => Ok(NativeResult::err(cost, EMALFORMED_PUBLIC_KEY)),
+    }
+}
+
+pub fn make_native_auth_key_from_ed25519_pubkey(
+    gas_params: AuthKeyFromPubkeyGasParameters,
+) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        native_auth_key_from_ed25519_pubkey(&gas_params, context, ty_args, args)
+    })
+}
+
+pub fn make_native_auth_key_from_multi_ed25519_pubkey(
+    gas_params: AuthKeyFromPubkeyGasParameters,
+) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        native_auth_key_from_multi_ed25519_pubkey(&gas_params, context, ty_args, args)
+    })
+}
+
 /***************************************************************************************************
  * module
  *
@@ -93,7 +265,11 @@ pub fn make_native_create_signer(gas_params: CreateSignerGasParameters) -> Nativ
 #[derive(Debug, Clone)]
 pub struct GasParameters {
     pub create_address: CreateAddressGasParameters,
+    pub create_object_address: CreateObjectAddressGasParameters,
     pub create_signer: CreateSignerGasParameters,
+    pub create_signers: CreateSignersGasParameters,
+    pub auth_key_from_ed25519_pubkey: AuthKeyFromPubkeyGasParameters,
+    pub auth_key_from_multi_ed25519_pubkey: AuthKeyFromPubkeyGasParameters,
 }
 
 pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
@@ -102,10 +278,28 @@ pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, Nati
             "create_address",
             make_native_create_address(gas_params.create_address),
         ),
+        (
+            "create_object_address",
+            make_native_create_object_address(gas_params.create_object_address),
+        ),
         (
             "create_signer",
             make_native_create_signer(gas_params.create_signer),
         ),
+        (
+            "create_signers",
+            make_native_create_signers(gas_params.create_signers),
+        ),
+        (
+            "auth_key_from_ed25519_pubkey",
+            make_native_auth_key_from_ed25519_pubkey(gas_params.auth_key_from_ed25519_pubkey),
+        ),
+        (
+            "auth_key_from_multi_ed25519_pubkey",
+            make_native_auth_key_from_multi_ed25519_pubkey(
+                gas_params.auth_key_from_multi_ed25519_pubkey,
+            ),
+        ),
     ];
 
     crate::natives::helpers::make_module_natives(natives)
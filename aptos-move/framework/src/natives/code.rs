@@ -5,10 +5,13 @@ use anyhow::bail;
 use aptos_types::transaction::ModuleBundle;
 use aptos_types::vm_status::StatusCode;
 use better_any::{Tid, TidAble};
+use move_deps::move_binary_format::access::ModuleAccess;
 use move_deps::move_binary_format::errors::PartialVMError;
+use move_deps::move_binary_format::file_format_common::BinaryConstants;
+use move_deps::move_binary_format::CompiledModule;
 use move_deps::move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes};
 use move_deps::move_vm_types::pop_arg;
-use move_deps::move_vm_types::values::Struct;
+use move_deps::move_vm_types::values::{Struct, Vector};
 use move_deps::{
     move_binary_format::errors::PartialVMResult,
     move_core_types::account_address::AccountAddress,
@@ -153,6 +156,10 @@ const EALREADY_REQUESTED: u64 = 0x03_0000;
 
 const CHECK_COMPAT_POLICY: u8 = 1;
 
+/// Abort code when module bytes fail to deserialize (0x01 == INVALID_ARGUMENT)
+/// NOTE: This must match the code in the Move implementation
+const EMODULE_DESERIALIZATION: u64 = 0x01_0005;
+
 /// The native code context.
 #[derive(Tid, Default)]
 pub struct NativeCodeContext {
@@ -250,6 +257,118 @@ pub fn make_native_request_publish(gas_params: RequestPublishGasParameters) -> N
     })
 }
 
+/***************************************************************************************************
+ * native fun module_friends_internal(code: vector<u8>): (vector<address>, vector<vector<u8>>)
+ *
+ *   gas cost: base_cost + per_byte_cost * bytes_len
+ *
+ **************************************************************************************************/
+#[derive(Clone, Debug)]
+pub struct ModuleFriendsGasParameters {
+    pub base_cost: InternalGas,
+    pub per_byte_cost: InternalGasPerByte,
+}
+
+/// Returns the addresses and the names of the friends declared by the serialized module `code`, as
+/// two vectors of the same length, or `None` if `code` does not deserialize.
+fn module_friends(code: &[u8]) -> Option<(Vec<AccountAddress>, Vec<Vec<u8>>)> {
+    let module = CompiledModule::deserialize(code).ok()?;
+    Some(
+        module
+            .immediate_friends()
+            .into_iter()
+            .map(|id| (*id.address(), id.name().as_bytes().to_vec()))
+            .unzip(),
+    )
+}
+
+/// Returns the addresses and the names of the friends declared by a module, as two vectors of the
+/// same length.
+fn native_module_friends(
+    gas_params: &ModuleFriendsGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert_eq!(args.len(), 1);
+
+    let code = pop_arg!(args, Vec<u8>);
+
+    let cost = gas_params.base_cost + gas_params.per_byte_cost * NumBytes::new(code.len() as u64);
+
+    let (addresses, names) = match module_friends(&code) {
+        Some(friends) => friends,
+        None => return Ok(NativeResult::err(cost, EMODULE_DESERIALIZATION)),
+    };
+    let names: Vec<_> = names.into_iter().map(Value::vector_u8).collect();
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![
+            Value::vector_address(addresses),
+            Vector::pack(&Type::Vector(Box::new(Type::U8)), names)?
+        ],
+    ))
+}
+
+pub fn make_native_module_friends(gas_params: ModuleFriendsGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        native_module_friends(&gas_params, context, ty_args, args)
+    })
+}
+
+/***************************************************************************************************
+ * native fun module_bytecode_version(code: vector<u8>): u64
+ *
+ *   gas cost: base_cost + per_byte_cost * header_len
+ *
+ **************************************************************************************************/
+#[derive(Clone, Debug)]
+pub struct BytecodeVersionGasParameters {
+    pub base_cost: InternalGas,
+    pub per_byte_cost: InternalGasPerByte,
+}
+
+/// Returns the bytecode version of a serialized module, read from the header, i.e., the 4-byte
+/// magic followed by the version as a little-endian u32, without deserializing the rest.
+fn native_module_bytecode_version(
+    gas_params: &BytecodeVersionGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert_eq!(args.len(), 1);
+
+    let code = pop_arg!(args, Vec<u8>);
+
+    let header_len = BinaryConstants::MOVE_MAGIC_SIZE + 4;
+    let cost = gas_params.base_cost
+        + gas_params.per_byte_cost * NumBytes::new(code.len().min(header_len) as u64);
+
+    if code.len() < header_len
+        || code[..BinaryConstants::MOVE_MAGIC_SIZE] != BinaryConstants::MOVE_MAGIC
+    {
+        return Ok(NativeResult::err(cost, EMODULE_DESERIALIZATION));
+    }
+    let mut version = [0u8; 4];
+    version.copy_from_slice(&code[BinaryConstants::MOVE_MAGIC_SIZE..header_len]);
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::u64(u32::from_le_bytes(version) as u64)],
+    ))
+}
+
+pub fn make_native_module_bytecode_version(
+    gas_params: BytecodeVersionGasParameters,
+) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        native_module_bytecode_version(&gas_params, context, ty_args, args)
+    })
+}
+
 /***************************************************************************************************
  * module
  *
@@ -257,13 +376,86 @@ pub fn make_native_request_publish(gas_params: RequestPublishGasParameters) -> N
 #[derive(Debug, Clone)]
 pub struct GasParameters {
     pub request_publish: RequestPublishGasParameters,
+    pub module_friends: ModuleFriendsGasParameters,
+    pub module_bytecode_version: BytecodeVersionGasParameters,
 }
 
 pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
-    let natives = [(
-        "request_publish",
-        make_native_request_publish(gas_params.request_publish),
-    )];
+    let natives = [
+        (
+            "request_publish",
+            make_native_request_publish(gas_params.request_publish),
+        ),
+        (
+            "module_friends_internal",
+            make_native_module_friends(gas_params.module_friends),
+        ),
+        (
+            "module_bytecode_version",
+            make_native_module_bytecode_version(gas_params.module_bytecode_version),
+        ),
+    ];
 
     crate::natives::helpers::make_module_natives(natives)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use move_deps::{
+        move_binary_format::{
+            file_format::{
+                AddressIdentifierIndex, IdentifierIndex, ModuleHandle, ModuleHandleIndex,
+            },
+            file_format_common::VERSION_MAX,
+        },
+        move_core_types::identifier::Identifier,
+    };
+
+    #[test]
+    fn test_module_friends() {
+        let module_handle = |address, name| ModuleHandle {
+            address: AddressIdentifierIndex(address),
+            name: IdentifierIndex(name),
+        };
+        let module = CompiledModule {
+            version: VERSION_MAX,
+            self_module_handle_idx: ModuleHandleIndex(0),
+            module_handles: vec![module_handle(0, 0)],
+            identifiers: ["hello", "friend_a", "friend_b"]
+                .iter()
+                .map(|name| Identifier::new(*name).unwrap())
+                .collect(),
+            address_identifiers: vec![
+                AccountAddress::from_hex_literal("0xcafe").unwrap(),
+                AccountAddress::ONE,
+            ],
+            friend_decls: vec![module_handle(0, 1), module_handle(1, 2)],
+            ..Default::default()
+        };
+        let mut code = vec![];
+        module.serialize(&mut code).unwrap();
+
+        // The addresses and the names are paired up by position.
+        assert_eq!(
+            module_friends(&code),
+            Some((
+                vec![
+                    AccountAddress::from_hex_literal("0xcafe").unwrap(),
+                    AccountAddress::ONE
+                ],
+                vec![b"friend_a".to_vec(), b"friend_b".to_vec()],
+            ))
+        );
+
+        let no_friends = CompiledModule {
+            friend_decls: vec![],
+            ..module
+        };
+        let mut code = vec![];
+        no_friends.serialize(&mut code).unwrap();
+        assert_eq!(module_friends(&code), Some((vec![], vec![])));
+
+        assert_eq!(module_friends(&[0xde, 0xad, 0xbe, 0xef]), None);
+    }
+}
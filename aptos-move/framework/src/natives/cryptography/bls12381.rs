@@ -17,7 +17,10 @@ use move_deps::{
     },
 };
 use smallvec::smallvec;
-use std::{collections::VecDeque, convert::TryFrom};
+use std::{
+    collections::{HashSet, VecDeque},
+    convert::TryFrom,
+};
 
 /// Pops a Vec<T> off the argument stack and converts it to a Vec<Vec<u8>> by reading the first
 /// field of T, which is a Vec<u8> field named `bytes`.
@@ -56,6 +59,9 @@ pub struct GasParameters {
 
     pub per_msg_hashing_cost: InternalGasPerArg,
     pub per_byte_hashing_cost: InternalGasPerByte, // signature verification involves signing |msg| bytes
+
+    pub per_distinct_msg_check_cost: InternalGasPerArg, // checking that aggregated messages are distinct
+    pub per_distinct_msg_byte_check_cost: InternalGasPerByte, // hashing and comparing their bytes
 }
 
 impl GasParameters {
@@ -201,36 +207,54 @@ impl GasParameters {
         debug_assert!(_ty_args.is_empty());
         debug_assert!(arguments.len() == 3);
 
-        let mut cost = self.base_cost;
         let msg_bytes = pop_arg!(arguments, Vec<u8>);
         let aggpk_bytes = pop_arg!(arguments, Vec<u8>);
         let multisig_bytes = pop_arg!(arguments, Vec<u8>);
 
+        let (cost, verify_result) = self.bls12381_verify_signature(
+            multisig_bytes,
+            aggpk_bytes,
+            msg_bytes,
+            check_pk_subgroup,
+        );
+
+        Ok(NativeResult::ok(
+            cost,
+            smallvec![Value::bool(verify_result)],
+        ))
+    }
+
+    /// Deserializes the public key and the signature, optionally checks the public key for
+    /// prime-order subgroup membership, and verifies the signature on `msg_bytes`, as done by
+    /// `bls12381_verify_signature_helper`. Returns the gas cost of these steps and whether the
+    /// signature verified. Also used to run test vectors through the natives' own logic.
+    pub(crate) fn bls12381_verify_signature(
+        &self,
+        multisig_bytes: Vec<u8>,
+        aggpk_bytes: Vec<u8>,
+        msg_bytes: Vec<u8>,
+        check_pk_subgroup: bool,
+    ) -> (InternalGas, bool) {
+        let mut cost = self.base_cost;
+
         let pk = match self.bls12381_deserialize_pk(aggpk_bytes, &mut cost) {
             Some(pk) => pk,
-            None => {
-                return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
-            }
+            None => return (cost, false),
         };
 
         if check_pk_subgroup && !self.bls12381_pk_subgroub_check(&pk, &mut cost) {
-            return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
+            return (cost, false);
         }
 
         let sig = match self.bls12381_deserialize_sig(multisig_bytes, &mut cost) {
             Some(sig) => sig,
-            None => {
-                return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
-            }
+            None => return (cost, false),
         };
 
         // NOTE(Gas): 2 bilinear pairings and a hash-to-curve
         let verify_result = self.signature_verify(&sig, &pk, msg_bytes, &mut cost);
 
-        Ok(NativeResult::ok(
-            cost,
-            smallvec![Value::bool(verify_result)],
-        ))
+        (cost, verify_result)
     }
 }
 
@@ -376,6 +400,45 @@ pub fn native_bls12381_aggregate_signatures(
     ))
 }
 
+/***************************************************************************************************
+ * native fun bls12381_hash_to_g2
+ *
+ *   gas cost: base_cost + per_msg_hashing_cost + per_byte_hashing_cost * (|msg| + |dst|)
+ *
+ * Hashes `msg` to a prime-order point in G2 via the BLS12381G2_XMD:SHA-256_SSWU_RO_ suite of
+ * RFC 9380, using `dst` as the domain separation tag.
+ **************************************************************************************************/
+fn native_bls12381_hash_to_g2(
+    gas_params: &GasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 2);
+
+    let dst = pop_arg!(arguments, Vec<u8>);
+    let msg = pop_arg!(arguments, Vec<u8>);
+
+    // NOTE(Gas): a hash-to-curve over both the message and the DST
+    let cost = gas_params.base_cost
+        + gas_params.per_msg_hashing_cost * NumArgs::one()
+        + gas_params.per_byte_hashing_cost * NumBytes::new((msg.len() + dst.len()) as u64);
+
+    // blst only exposes hash-to-G2 through signing, so we "sign" with the secret key 1, which
+    // returns the hashed point H(msg) unchanged.
+    let mut sk_one = [0u8; 32];
+    sk_one[31] = 1;
+    let sk = blst::min_pk::SecretKey::from_bytes(&sk_one)
+        .expect("1 is always a valid BLS12-381 secret key");
+    let point = sk.sign(&msg, &dst, &[]);
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(point.to_bytes().to_vec())],
+    ))
+}
+
 /***************************************************************************************************
  * native fun bls12381_signature_subgroup_check
  *
@@ -514,11 +577,109 @@ pub fn native_bls12381_verify_aggregate_signature(
     // the messages to elliptic curve points (proportional to sum of all message lengths).
     cost += gas_params.per_pairing_cost * NumArgs::new((messages.len() + 1) as u64)
         + gas_params.per_msg_hashing_cost * NumArgs::new(messages.len() as u64)
+        + gas_params.per_byte_hashing_cost * total_msg_bytes;
+
+    let verify_result = aggsig
+        .verify_aggregate_arbitrary_msg(&msgs_refs, &pks_refs)
+        .is_ok();
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::bool(verify_result)],
+    ))
+}
+
+/***************************************************************************************************
+ * native fun bls12381_aggregate_verify_distinct
+ *
+ *   gas cost: base_cost + num_pubkeys * per_distinct_msg_check_cost
+ *                       + total_msg_bytes * per_distinct_msg_byte_check_cost
+ *                       +? ( num_validatable_pubkeys * per_pubkey_deserialize_cost
+ *                            +? ( num_pubkeys * per_pubkey_subgroup_check_cost
+ *                                 +? ( per_sig_deserialize_cost
+ *                                      +? ( per_pairing_cost * (num_pubkeys + 1)
+ *                                           + per_msg_hashing_cost * num_pubkeys
+ *                                           + per_byte_hashing_cost * total_msg_bytes ) ) ) )
+ *
+ * where +? indicates that the expression stops evaluating there if the previous gas-charging step
+ * failed, num_pubkeys is the # of public keys given as input and total_msg_bytes is the cumulative
+ * size in bytes of all messages.
+ *
+ * NOTE: Unlike `bls12381_verify_aggregate_signature`, the public keys need not come with verified
+ * PoPs. Rogue-key attacks are instead prevented by requiring all messages to be distinct, as in the
+ * basic scheme of the IETF BLS draft standard, and small-subgroup attacks by subgroup-checking every
+ * public key.
+ **************************************************************************************************/
+fn native_bls12381_aggregate_verify_distinct(
+    gas_params: &GasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 3);
+
+    let mut cost = gas_params.base_cost;
+
+    let aggsig_bytes = pop_arg!(arguments, Vec<u8>);
+    let messages = pop_vec_arg!(arguments, Vec<u8>);
+    let pks_serialized = pop_vec_arg!(arguments, Vec<u8>);
+    let num_pks = pks_serialized.len();
+
+    // Number of messages must match number of public keys, and there must be at least one of each
+    if num_pks != messages.len() || num_pks == 0 {
+        return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
+    }
+
+    let total_msg_bytes = messages.iter().fold(NumBytes::new(0), |sum, msg| {
+        sum + NumBytes::new(msg.len() as u64)
+    });
+
+    // All messages must be distinct. NOTE(Gas): a hash set does the check in time linear in the
+    // total size of the messages, which is charged before building it.
+    cost += gas_params.per_distinct_msg_check_cost * NumArgs::new(num_pks as u64)
+        + gas_params.per_distinct_msg_byte_check_cost * total_msg_bytes;
+    let distinct_msgs = messages
+        .iter()
+        .map(|m| m.as_slice())
+        .collect::<HashSet<&[u8]>>();
+    if distinct_msgs.len() != num_pks {
+        return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
+    }
+
+    let pks = gas_params.bls12381_deserialize_pks(pks_serialized, &mut cost);
+    debug_assert!(pks.len() <= num_pks);
+
+    if pks.len() != num_pks {
+        return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
+    }
+
+    for pk in &pks {
+        cost += gas_params.per_pubkey_subgroup_check_cost * NumArgs::one();
+        if pk.subgroup_check().is_err() {
+            return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
+        }
+    }
+
+    let aggsig = match gas_params.bls12381_deserialize_sig(aggsig_bytes, &mut cost) {
+        Some(aggsig) => aggsig,
+        None => return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)])),
+    };
+
+    let msgs_refs = messages
+        .iter()
+        .map(|m| m.as_slice())
+        .collect::<Vec<&[u8]>>();
+    let pks_refs = pks.iter().collect::<Vec<&bls12381::PublicKey>>();
+
+    cost += gas_params.per_pairing_cost * NumArgs::new((num_pks + 1) as u64)
+        + gas_params.per_msg_hashing_cost * NumArgs::new(num_pks as u64)
         + gas_params.per_byte_hashing_cost
             * messages.iter().fold(NumBytes::new(0), |sum, msg| {
                 sum + NumBytes::new(msg.len() as u64)
             });
 
+    // NOTE: This also subgroup-checks the aggregate signature.
     let verify_result = aggsig
         .verify_aggregate_arbitrary_msg(&msgs_refs, &pks_refs)
         .is_ok();
@@ -653,6 +814,17 @@ pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, Nati
             "aggregate_signatures_internal",
             make_native_from_func(gas_params.clone(), native_bls12381_aggregate_signatures),
         ),
+        (
+            "aggregate_verify_distinct_internal",
+            make_native_from_func(
+                gas_params.clone(),
+                native_bls12381_aggregate_verify_distinct,
+            ),
+        ),
+        (
+            "hash_to_g2_internal",
+            make_native_from_func(gas_params.clone(), native_bls12381_hash_to_g2),
+        ),
         (
             "signature_subgroup_check_internal",
             make_native_from_func(gas_params.clone(), native_bls12381_signature_subgroup_check),
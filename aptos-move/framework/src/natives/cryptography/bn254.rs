@@ -0,0 +1,350 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::pop_vec_arg;
+use ark_bn254::{Bn254, Fr, G1Affine, G1Projective, G2Affine, G2Projective};
+use ark_ec::{AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_ff::{One, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use move_deps::move_core_types::gas_algebra::{InternalGas, InternalGasPerArg, NumArgs};
+use move_deps::{
+    move_binary_format::errors::{PartialVMError, PartialVMResult},
+    move_core_types::vm_status::StatusCode,
+    move_vm_runtime::native_functions::{NativeContext, NativeFunction},
+    move_vm_types::{
+        loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+    },
+};
+use smallvec::smallvec;
+use std::{collections::VecDeque, sync::Arc};
+
+/// Abort code when a point fails to deserialize, is not on the curve or is not in the prime-order
+/// subgroup (0x01 == INVALID_ARGUMENT)
+/// NOTE: This must match the code in the Move implementation
+const EINVALID_POINT: u64 = 0x01_0001;
+
+/// Abort code when a scalar is not a canonical element of the scalar field
+const EINVALID_SCALAR: u64 = 0x01_0002;
+
+/// Abort code when `pairing_check` is given a different number of G1 and G2 points
+const ELENGTH_MISMATCH: u64 = 0x01_0003;
+
+/// Size of a compressed G1 point, of a compressed G2 point and of a scalar, in bytes.
+const G1_NUM_BYTES: usize = 32;
+const G2_NUM_BYTES: usize = 64;
+const SCALAR_NUM_BYTES: usize = 32;
+
+/// Deserializes a compressed G1 point. The arkworks deserialization checks that the point is on
+/// the curve and in the prime-order subgroup.
+fn deserialize_g1(bytes: &[u8]) -> Option<G1Affine> {
+    if bytes.len() != G1_NUM_BYTES {
+        return None;
+    }
+
+    G1Affine::deserialize(bytes).ok()
+}
+
+/// Deserializes a compressed G2 point. The arkworks deserialization checks that the point is on
+/// the curve and in the prime-order subgroup.
+fn deserialize_g2(bytes: &[u8]) -> Option<G2Affine> {
+    if bytes.len() != G2_NUM_BYTES {
+        return None;
+    }
+
+    G2Affine::deserialize(bytes).ok()
+}
+
+/// Deserializes a scalar, failing if it is not smaller than the scalar field order.
+fn deserialize_scalar(bytes: &[u8]) -> Option<Fr> {
+    if bytes.len() != SCALAR_NUM_BYTES {
+        return None;
+    }
+
+    Fr::deserialize(bytes).ok()
+}
+
+fn serialize<T: CanonicalSerialize>(t: &T) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(t.serialized_size());
+    // NOTE: serializing into a Vec cannot fail
+    t.serialize(&mut bytes).unwrap();
+    bytes
+}
+
+/***************************************************************************************************
+ * native fun g1_add
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct G1AddGasParameters {
+    pub base_cost: InternalGas,
+}
+
+fn native_g1_add(
+    gas_params: &G1AddGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 2);
+
+    let q_bytes = pop_arg!(arguments, Vec<u8>);
+    let p_bytes = pop_arg!(arguments, Vec<u8>);
+
+    let cost = gas_params.base_cost;
+
+    let (p, q) = match (deserialize_g1(&p_bytes), deserialize_g1(&q_bytes)) {
+        (Some(p), Some(q)) => (p, q),
+        _ => return Ok(NativeResult::err(cost, EINVALID_POINT)),
+    };
+
+    let sum = (G1Projective::from(p) + G1Projective::from(q)).into_affine();
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(serialize(&sum))],
+    ))
+}
+
+pub fn make_native_g1_add(gas_params: G1AddGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_g1_add(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun g1_mul
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct G1MulGasParameters {
+    pub base_cost: InternalGas,
+}
+
+fn native_g1_mul(
+    gas_params: &G1MulGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 2);
+
+    let scalar_bytes = pop_arg!(arguments, Vec<u8>);
+    let p_bytes = pop_arg!(arguments, Vec<u8>);
+
+    let cost = gas_params.base_cost;
+
+    let p = match deserialize_g1(&p_bytes) {
+        Some(p) => p,
+        None => return Ok(NativeResult::err(cost, EINVALID_POINT)),
+    };
+    let scalar = match deserialize_scalar(&scalar_bytes) {
+        Some(scalar) => scalar,
+        None => return Ok(NativeResult::err(cost, EINVALID_SCALAR)),
+    };
+
+    let product = p.mul(scalar.into_repr()).into_affine();
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(serialize(&product))],
+    ))
+}
+
+pub fn make_native_g1_mul(gas_params: G1MulGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_g1_mul(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun g2_add
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct G2AddGasParameters {
+    pub base_cost: InternalGas,
+}
+
+fn native_g2_add(
+    gas_params: &G2AddGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 2);
+
+    let q_bytes = pop_arg!(arguments, Vec<u8>);
+    let p_bytes = pop_arg!(arguments, Vec<u8>);
+
+    let cost = gas_params.base_cost;
+
+    let (p, q) = match (deserialize_g2(&p_bytes), deserialize_g2(&q_bytes)) {
+        (Some(p), Some(q)) => (p, q),
+        _ => return Ok(NativeResult::err(cost, EINVALID_POINT)),
+    };
+
+    let sum = (G2Projective::from(p) + G2Projective::from(q)).into_affine();
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(serialize(&sum))],
+    ))
+}
+
+pub fn make_native_g2_add(gas_params: G2AddGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_g2_add(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun g2_mul
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct G2MulGasParameters {
+    pub base_cost: InternalGas,
+}
+
+fn native_g2_mul(
+    gas_params: &G2MulGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 2);
+
+    let scalar_bytes = pop_arg!(arguments, Vec<u8>);
+    let p_bytes = pop_arg!(arguments, Vec<u8>);
+
+    let cost = gas_params.base_cost;
+
+    let p = match deserialize_g2(&p_bytes) {
+        Some(p) => p,
+        None => return Ok(NativeResult::err(cost, EINVALID_POINT)),
+    };
+    let scalar = match deserialize_scalar(&scalar_bytes) {
+        Some(scalar) => scalar,
+        None => return Ok(NativeResult::err(cost, EINVALID_SCALAR)),
+    };
+
+    let product = p.mul(scalar.into_repr()).into_affine();
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(serialize(&product))],
+    ))
+}
+
+pub fn make_native_g2_mul(gas_params: G2MulGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_g2_mul(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun pairing_check
+ *
+ *   gas cost: base_cost + per_g1_deserialize_cost * num_points
+ *                       +? ( per_g2_deserialize_cost * num_points
+ *                            +? per_pairing_cost * num_points )
+ *
+ * where +? indicates that the expression stops evaluating there if the previous gas-charging step
+ * failed, and num_points is the # of G1 points given as input (which must match the # of G2
+ * points).
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct PairingCheckGasParameters {
+    pub base_cost: InternalGas,
+    pub per_g1_deserialize_cost: InternalGasPerArg,
+    pub per_g2_deserialize_cost: InternalGasPerArg,
+    pub per_pairing_cost: InternalGasPerArg,
+}
+
+fn native_pairing_check(
+    gas_params: &PairingCheckGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 2);
+
+    let g2s_bytes = pop_vec_arg!(arguments, Vec<u8>);
+    let g1s_bytes = pop_vec_arg!(arguments, Vec<u8>);
+
+    let mut cost = gas_params.base_cost;
+
+    if g1s_bytes.len() != g2s_bytes.len() {
+        return Ok(NativeResult::err(cost, ELENGTH_MISMATCH));
+    }
+    let num_points = NumArgs::new(g1s_bytes.len() as u64);
+
+    cost += gas_params.per_g1_deserialize_cost * num_points;
+    let mut g1s = Vec::with_capacity(g1s_bytes.len());
+    for bytes in g1s_bytes {
+        match deserialize_g1(&bytes) {
+            Some(p) => g1s.push(p),
+            None => return Ok(NativeResult::err(cost, EINVALID_POINT)),
+        }
+    }
+
+    cost += gas_params.per_g2_deserialize_cost * num_points;
+    let mut g2s = Vec::with_capacity(g2s_bytes.len());
+    for bytes in g2s_bytes {
+        match deserialize_g2(&bytes) {
+            Some(p) => g2s.push(p),
+            None => return Ok(NativeResult::err(cost, EINVALID_POINT)),
+        }
+    }
+
+    // NOTE(Gas): the pairings are computed as a single multi-Miller loop, followed by a single
+    // final exponentiation
+    cost += gas_params.per_pairing_cost * num_points;
+    let pairs = g1s
+        .into_iter()
+        .zip(g2s.into_iter())
+        .map(|(p, q)| (p.into(), q.into()))
+        .collect::<Vec<_>>();
+    let result = Bn254::product_of_pairings(&pairs) == <Bn254 as PairingEngine>::Fqk::one();
+
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(result)]))
+}
+
+pub fn make_native_pairing_check(gas_params: PairingCheckGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        native_pairing_check(&gas_params, context, ty_args, args)
+    })
+}
+
+/***************************************************************************************************
+ * module
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub g1_add: G1AddGasParameters,
+    pub g1_mul: G1MulGasParameters,
+    pub g2_add: G2AddGasParameters,
+    pub g2_mul: G2MulGasParameters,
+    pub pairing_check: PairingCheckGasParameters,
+}
+
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [
+        ("g1_add", make_native_g1_add(gas_params.g1_add)),
+        ("g1_mul", make_native_g1_mul(gas_params.g1_mul)),
+        ("g2_add", make_native_g2_add(gas_params.g2_add)),
+        ("g2_mul", make_native_g2_mul(gas_params.g2_mul)),
+        (
+            "pairing_check",
+            make_native_pairing_check(gas_params.pairing_check),
+        ),
+    ];
+
+    crate::natives::helpers::make_module_natives(natives)
+}
@@ -90,22 +90,35 @@ fn native_signature_verify_strict(
     let pubkey = pop_arg!(arguments, Vec<u8>);
     let signature = pop_arg!(arguments, Vec<u8>);
 
+    let (cost, verify_result) = signature_verify_strict(gas_params, &signature, &pubkey, &msg);
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::bool(verify_result)],
+    ))
+}
+
+/// Deserializes the public key and the signature and strictly verifies the signature on `msg`, as
+/// done by `signature_verify_strict_internal`. Returns the gas cost of these steps and whether the
+/// signature verified. Also used to run test vectors through the native's own logic.
+pub(crate) fn signature_verify_strict(
+    gas_params: &GasParameters,
+    signature: &[u8],
+    pubkey: &[u8],
+    msg: &[u8],
+) -> (InternalGas, bool) {
     let mut cost = gas_params.base_cost;
 
     cost += gas_params.per_pubkey_deserialize_cost * NumArgs::one();
-    let pk = match ed25519::Ed25519PublicKey::try_from(pubkey.as_slice()) {
+    let pk = match ed25519::Ed25519PublicKey::try_from(pubkey) {
         Ok(pk) => pk,
-        Err(_) => {
-            return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
-        }
+        Err(_) => return (cost, false),
     };
 
     cost += gas_params.per_sig_deserialize_cost * NumArgs::one();
-    let sig = match ed25519::Ed25519Signature::try_from(signature.as_slice()) {
+    let sig = match ed25519::Ed25519Signature::try_from(signature) {
         Ok(sig) => sig,
-        Err(_) => {
-            return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
-        }
+        Err(_) => return (cost, false),
     };
 
     // NOTE(Gas): hashing the message to the group and a size-2 multi-scalar multiplication
@@ -113,11 +126,7 @@ fn native_signature_verify_strict(
         + gas_params.per_msg_hashing_base_cost * NumArgs::one()
         + gas_params.per_msg_byte_hashing_cost * NumBytes::new(msg.len() as u64);
 
-    let verify_result = sig.verify_arbitrary_msg(msg.as_slice(), &pk).is_ok();
-    Ok(NativeResult::ok(
-        cost,
-        smallvec![Value::bool(verify_result)],
-    ))
+    (cost, sig.verify_arbitrary_msg(msg, &pk).is_ok())
 }
 
 /***************************************************************************************************
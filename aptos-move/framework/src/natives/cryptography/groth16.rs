@@ -0,0 +1,130 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{natives::util::make_native_from_func, pop_vec_arg};
+use ark_bn254::{Bn254, Fr};
+use ark_groth16::{prepare_verifying_key, verify_proof, Proof, VerifyingKey};
+use ark_serialize::CanonicalDeserialize;
+use move_deps::move_core_types::gas_algebra::{InternalGas, InternalGasPerArg, NumArgs};
+use move_deps::{
+    move_binary_format::errors::PartialVMResult,
+    move_vm_runtime::native_functions::{NativeContext, NativeFunction},
+    move_vm_types::{
+        loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+    },
+};
+use smallvec::smallvec;
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone)]
+pub struct GasParameters {
+    pub base_cost: InternalGas,
+
+    pub per_vk_deserialize_cost: InternalGasPerArg,
+    // covers deserializing one of the `num_public_inputs + 1` points of `gamma_abc_g1` in the verifying key
+    pub per_vk_point_deserialize_cost: InternalGasPerArg,
+    pub per_proof_deserialize_cost: InternalGasPerArg,
+
+    // covers deserializing the input and a G1 scalar multiplication
+    pub per_public_input_cost: InternalGasPerArg,
+
+    pub per_pairing_cost: InternalGasPerArg, // Groth16 verification requires 3 pairings
+}
+
+/// Size of a compressed BN254 G1 point.
+const G1_COMPRESSED_SIZE: usize = 32;
+
+/// Size of a compressed verifying key without its `gamma_abc_g1` points, i.e., of `alpha_g1`,
+/// `beta_g2`, `gamma_g2` and `delta_g2` followed by the u64 length of `gamma_abc_g1`.
+const VK_FIXED_SIZE: usize = G1_COMPRESSED_SIZE + 3 * 64 + 8;
+
+/***************************************************************************************************
+ * native fun groth16_verify
+ *
+ *   gas cost: base_cost
+ *             +? ( per_vk_deserialize_cost + per_vk_point_deserialize_cost * (num_public_inputs + 1)
+ *                  +? ( per_proof_deserialize_cost
+ *                       +? ( per_public_input_cost * num_public_inputs
+ *                            +? per_pairing_cost * 3 ) ) )
+ *
+ * where +? indicates that the expression stops evaluating there if the previous gas-charging step
+ * failed, and num_public_inputs is the # of public inputs given as input. The first step fails
+ * without deserializing anything if the verifying key is not exactly the size of a key with
+ * num_public_inputs + 1 points in gamma_abc_g1, so its cost is bounded by what is charged.
+ *
+ * NOTE: The verifying key, the proof and the public inputs are expected in the canonical
+ * (compressed) arkworks serialization. Deserialization checks that all points are on the curve
+ * and in the prime-order subgroup, and that all public inputs are canonical field elements.
+ * Any malformed input makes the verification fail.
+ **************************************************************************************************/
+fn native_groth16_verify(
+    gas_params: &GasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 3);
+
+    let proof_bytes = pop_arg!(arguments, Vec<u8>);
+    let public_inputs_bytes = pop_vec_arg!(arguments, Vec<u8>);
+    let vk_bytes = pop_arg!(arguments, Vec<u8>);
+
+    let mut cost = gas_params.base_cost;
+
+    // The verifying key has one point per public input, plus one for the constant term.
+    let num_vk_points = public_inputs_bytes.len() + 1;
+    if vk_bytes.len() != VK_FIXED_SIZE + G1_COMPRESSED_SIZE * num_vk_points {
+        return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
+    }
+
+    cost += gas_params.per_vk_deserialize_cost * NumArgs::one()
+        + gas_params.per_vk_point_deserialize_cost * NumArgs::new(num_vk_points as u64);
+    let vk = match VerifyingKey::<Bn254>::deserialize(vk_bytes.as_slice()) {
+        Ok(vk) => vk,
+        Err(_) => return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)])),
+    };
+
+    cost += gas_params.per_proof_deserialize_cost * NumArgs::one();
+    let proof = match Proof::<Bn254>::deserialize(proof_bytes.as_slice()) {
+        Ok(proof) => proof,
+        Err(_) => return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)])),
+    };
+
+    // The encoded length of gamma_abc_g1 may still disagree with the size checked above.
+    if num_vk_points != vk.gamma_abc_g1.len() {
+        return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)]));
+    }
+
+    cost += gas_params.per_public_input_cost * NumArgs::new(public_inputs_bytes.len() as u64);
+    let mut public_inputs = Vec::with_capacity(public_inputs_bytes.len());
+    for input_bytes in public_inputs_bytes {
+        match Fr::deserialize(input_bytes.as_slice()) {
+            Ok(input) => public_inputs.push(input),
+            Err(_) => return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)])),
+        }
+    }
+
+    // NOTE(Gas): 3 bilinear pairings (computed as a single multi-Miller loop)
+    cost += gas_params.per_pairing_cost * NumArgs::new(3);
+    let pvk = prepare_verifying_key(&vk);
+    let verify_result = verify_proof(&pvk, &proof, &public_inputs).unwrap_or(false);
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::bool(verify_result)],
+    ))
+}
+
+/***************************************************************************************************
+ * module
+ *
+ **************************************************************************************************/
+pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
+    let natives = [(
+        "verify_proof_internal",
+        make_native_from_func(gas_params, native_groth16_verify),
+    )];
+
+    crate::natives::helpers::make_module_natives(natives)
+}
@@ -2,5 +2,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub mod bls12381;
+pub mod bn254;
 pub mod ed25519;
+pub mod groth16;
 pub mod secp256k1;
+
+#[cfg(test)]
+mod test_vectors;
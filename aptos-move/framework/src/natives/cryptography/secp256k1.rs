@@ -15,6 +15,7 @@ use move_deps::move_vm_types::loaded_data::runtime_types::Type;
 use move_deps::move_vm_types::natives::function::NativeResult;
 use move_deps::move_vm_types::pop_arg;
 use move_deps::move_vm_types::values::Value;
+use sha3::{Digest, Keccak256};
 use smallvec::smallvec;
 use std::collections::VecDeque;
 
@@ -37,53 +38,110 @@ fn native_ecdsa_recover(
     let recovery_id = pop_arg!(arguments, u8);
     let msg = pop_arg!(arguments, Vec<u8>);
 
+    match ecdsa_recover(gas_params, &msg, recovery_id, &signature) {
+        (cost, Err(abort_code)) => Ok(NativeResult::err(cost, abort_code)),
+        (cost, Ok(Some(pk))) => Ok(NativeResult::ok(
+            cost,
+            smallvec![Value::vector_u8(pk), Value::bool(true)],
+        )),
+        (cost, Ok(None)) => Ok(NativeResult::ok(
+            cost,
+            smallvec![Value::vector_u8([0u8; 0]), Value::bool(false)],
+        )),
+    }
+}
+
+/// Deserializes the message, the recovery ID and the signature and recovers the 64-byte public key,
+/// as done by `ecdsa_recover_internal`. Returns the gas cost of these steps, and either the
+/// recovered public key (`None` if recovery failed) or the abort code of a deserialization
+/// failure. Also used to run test vectors through the native's own logic.
+pub(crate) fn ecdsa_recover(
+    gas_params: &GasParameters,
+    msg: &[u8],
+    recovery_id: u8,
+    signature: &[u8],
+) -> (InternalGas, Result<Option<Vec<u8>>, u64>) {
     let mut cost = gas_params.base_cost;
 
     // NOTE(Gas): O(1) cost
     // (In reality, O(|msg|) deserialization cost, with |msg| < libsecp256k1_core::util::MESSAGE_SIZE
     // which seems to be 32 bytes, so O(1) cost for all intents and purposes.)
-    let msg = match libsecp256k1::Message::parse_slice(&msg) {
+    let msg = match libsecp256k1::Message::parse_slice(msg) {
         Ok(msg) => msg,
-        Err(_) => {
-            return Ok(NativeResult::err(cost, abort_codes::NFE_DESERIALIZE));
-        }
+        Err(_) => return (cost, Err(abort_codes::NFE_DESERIALIZE)),
     };
 
     // NOTE(Gas): O(1) cost
     let rid = match libsecp256k1::RecoveryId::parse(recovery_id) {
         Ok(rid) => rid,
-        Err(_) => {
-            return Ok(NativeResult::err(cost, abort_codes::NFE_DESERIALIZE));
-        }
+        Err(_) => return (cost, Err(abort_codes::NFE_DESERIALIZE)),
     };
 
     // NOTE(Gas): O(1) deserialization cost
     // which seems to be 64 bytes, so O(1) cost for all intents and purposes.
-    let sig = match libsecp256k1::Signature::parse_standard_slice(&signature) {
+    let sig = match libsecp256k1::Signature::parse_standard_slice(signature) {
         Ok(sig) => sig,
-        Err(_) => {
-            return Ok(NativeResult::err(cost, abort_codes::NFE_DESERIALIZE));
-        }
+        Err(_) => return (cost, Err(abort_codes::NFE_DESERIALIZE)),
     };
 
     cost += gas_params.ecdsa_recover_cost * NumArgs::one();
 
     // NOTE(Gas): O(1) cost: a size-2 multi-scalar multiplication
     match libsecp256k1::recover(&msg, &sig, &rid) {
-        Ok(pk) => Ok(NativeResult::ok(
-            cost,
-            smallvec![
-                Value::vector_u8(pk.serialize()[1..].to_vec()),
-                Value::bool(true)
-            ],
-        )),
-        Err(_) => Ok(NativeResult::ok(
-            cost,
-            smallvec![Value::vector_u8([0u8; 0]), Value::bool(false)],
-        )),
+        Ok(pk) => (cost, Ok(Some(pk.serialize()[1..].to_vec()))),
+        Err(_) => (cost, Ok(None)),
     }
 }
 
+/***************************************************************************************************
+ * native fun ecdsa_verify_eth_address
+ *
+ *   gas cost: base_cost + ecdsa_recover + eth_address
+ *
+ **************************************************************************************************/
+fn native_ecdsa_verify_eth_address(
+    gas_params: &GasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(arguments.len() == 3);
+
+    let expected_address = pop_arg!(arguments, Vec<u8>);
+    let signature = pop_arg!(arguments, Vec<u8>);
+    let msg = pop_arg!(arguments, Vec<u8>);
+
+    // The signature is r || s || v, where Ethereum encodes the recovery ID v as either 0/1 (legacy)
+    // or 27/28. Any other v, even if it is a recovery ID libsecp256k1 accepts, is rejected.
+    let (sig, recovery_id) = match signature.split_last() {
+        Some((&v, sig)) if v == 0 || v == 1 => (sig, v),
+        Some((&v, sig)) if v == 27 || v == 28 => (sig, v - 27),
+        _ => {
+            return Ok(NativeResult::err(
+                gas_params.base_cost,
+                abort_codes::NFE_DESERIALIZE,
+            ));
+        }
+    };
+
+    let (mut cost, pk) = match ecdsa_recover(gas_params, &msg, recovery_id, sig) {
+        (cost, Err(abort_code)) => return Ok(NativeResult::err(cost, abort_code)),
+        (cost, Ok(None)) => return Ok(NativeResult::ok(cost, smallvec![Value::bool(false)])),
+        (cost, Ok(Some(pk))) => (cost, pk),
+    };
+
+    cost += gas_params.eth_address_cost * NumArgs::one();
+
+    // The Ethereum address is the last 20 bytes of the Keccak-256 hash of the 64-byte public key.
+    let address = &Keccak256::digest(&pk)[12..];
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::bool(address == expected_address.as_slice())],
+    ))
+}
+
 /***************************************************************************************************
  * module
  *
@@ -92,13 +150,20 @@ fn native_ecdsa_recover(
 pub struct GasParameters {
     pub base_cost: InternalGas,
     pub ecdsa_recover_cost: InternalGasPerArg,
+    pub eth_address_cost: InternalGasPerArg,
 }
 
 pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
-    let natives = [(
-        "ecdsa_recover_internal",
-        make_native_from_func(gas_params, native_ecdsa_recover),
-    )];
+    let natives = [
+        (
+            "ecdsa_recover_internal",
+            make_native_from_func(gas_params.clone(), native_ecdsa_recover),
+        ),
+        (
+            "ecdsa_verify_eth_address",
+            make_native_from_func(gas_params, native_ecdsa_verify_eth_address),
+        ),
+    ];
 
     crate::natives::helpers::make_module_natives(natives)
 }
@@ -0,0 +1,121 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+
+//! A harness for running signature test vectors (e.g., from Wycheproof) through the same
+//! deserialization and verification code as the signature natives: Ed25519 strict verification,
+//! secp256k1 ECDSA public key recovery and BLS12-381 normal signature verification. There is no
+//! MultiEd25519 native in the framework, so MultiEd25519 is not covered.
+
+use crate::natives::{
+    cryptography::{bls12381, ed25519, secp256k1},
+    GasParameters,
+};
+use serde::Deserialize;
+use std::path::Path;
+
+/// A single test case, with all byte fields hex-encoded.
+#[derive(Debug, Deserialize)]
+struct TestCase {
+    /// One of "ed25519", "secp256k1" or "bls12381".
+    algo: String,
+    /// For secp256k1, the 64-byte uncompressed public key without its 0x04 prefix, as returned by
+    /// `secp256k1::ecdsa_recover`.
+    pubkey: String,
+    /// For secp256k1, the 32-byte message hash.
+    message: String,
+    /// For secp256k1, the 64-byte signature followed by the 1-byte recovery ID.
+    signature: String,
+    /// Whether the signature is expected to verify.
+    expected: bool,
+}
+
+/// Runs `ed25519::signature_verify_strict_internal`.
+fn ed25519_verify(
+    gas_params: &ed25519::GasParameters,
+    pubkey: &[u8],
+    message: &[u8],
+    signature: &[u8],
+) -> bool {
+    ed25519::signature_verify_strict(gas_params, signature, pubkey, message).1
+}
+
+/// Runs `secp256k1::ecdsa_recover_internal` and compares the recovered public key with the expected
+/// one. Deserialization failures, which abort in the native, count as a failed verification.
+fn secp256k1_verify(
+    gas_params: &secp256k1::GasParameters,
+    pubkey: &[u8],
+    message: &[u8],
+    signature: &[u8],
+) -> bool {
+    let (signature, recovery_id) = match signature.split_last() {
+        Some((recovery_id, signature)) => (signature, *recovery_id),
+        None => return false,
+    };
+
+    match secp256k1::ecdsa_recover(gas_params, message, recovery_id, signature).1 {
+        Ok(Some(recovered)) => recovered == pubkey,
+        Ok(None) | Err(_) => false,
+    }
+}
+
+/// Runs `bls12381::verify_normal_signature_internal`, which subgroup-checks the public key.
+fn bls12381_verify(
+    gas_params: &bls12381::GasParameters,
+    pubkey: &[u8],
+    message: &[u8],
+    signature: &[u8],
+) -> bool {
+    gas_params
+        .bls12381_verify_signature(signature.to_vec(), pubkey.to_vec(), message.to_vec(), true)
+        .1
+}
+
+/// Runs the test cases in the JSON file at `path`, which holds an array of
+/// `{algo, pubkey, message, signature, expected}` objects, and reports every case whose outcome
+/// differs from `expected`.
+pub(crate) fn run_signature_test_vectors(path: &Path) -> Result<(), String> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let cases: Vec<TestCase> = serde_json::from_str(&contents)
+        .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?;
+
+    let gas_params = GasParameters::zeros();
+
+    let mut mismatches = vec![];
+    for (i, case) in cases.iter().enumerate() {
+        let decode = |field: &str, value: &str| {
+            hex::decode(value).map_err(|e| format!("Case #{}: invalid hex in {}: {}", i, field, e))
+        };
+        let pubkey = decode("pubkey", &case.pubkey)?;
+        let message = decode("message", &case.message)?;
+        let signature = decode("signature", &case.signature)?;
+
+        let verified = match case.algo.as_str() {
+            "ed25519" => ed25519_verify(&gas_params.ed25519, &pubkey, &message, &signature),
+            "secp256k1" => secp256k1_verify(&gas_params.secp256k1, &pubkey, &message, &signature),
+            "bls12381" => bls12381_verify(&gas_params.bls12381, &pubkey, &message, &signature),
+            algo => return Err(format!("Case #{}: unknown algorithm {}", i, algo)),
+        };
+
+        if verified != case.expected {
+            mismatches.push(format!(
+                "Case #{} ({}): expected {}, got {}",
+                i, case.algo, case.expected, verified
+            ));
+        }
+    }
+
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(mismatches.join("\n"))
+    }
+}
+
+#[test]
+fn test_signature_test_vectors() {
+    let path = crate::path_in_crate("test_vectors/signatures.json");
+    if let Err(mismatches) = run_signature_test_vectors(&path) {
+        panic!("{}", mismatches);
+    }
+}
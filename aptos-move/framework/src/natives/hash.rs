@@ -1,17 +1,78 @@
 // Copyright (c) Aptos
 // SPDX-License-Identifier: Apache-2.0
 
+use crate::pop_vec_arg;
+use aptos_crypto::{
+    hash::{CryptoHash, CryptoHasher},
+    HashValue,
+};
+use aptos_types::{proof::TransactionAccumulatorInternalNode, transaction::TransactionInfoHasher};
+use crc::{Crc, CRC_32_ISCSI, CRC_32_ISO_HDLC};
 use move_deps::{
-    move_binary_format::errors::PartialVMResult,
-    move_core_types::gas_algebra::{InternalGas, InternalGasPerByte, NumBytes},
+    move_binary_format::errors::{PartialVMError, PartialVMResult},
+    move_core_types::{
+        gas_algebra::{InternalGas, InternalGasPerArg, InternalGasPerByte, NumArgs, NumBytes},
+        vm_status::StatusCode,
+    },
     move_vm_runtime::native_functions::{NativeContext, NativeFunction},
     move_vm_types::{
-        loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+        loaded_data::runtime_types::Type,
+        natives::function::NativeResult,
+        pop_arg,
+        values::{Reference, Value},
     },
 };
+use sha2::Sha256;
+use sha3::{Digest, Keccak256, Sha3_256};
 use smallvec::smallvec;
 use std::{collections::VecDeque, hash::Hasher, sync::Arc};
 
+/// Abort code when an unknown hash algorithm is requested (0x01 == INVALID_ARGUMENT)
+/// NOTE: This must match the code in the Move implementation
+const EUNKNOWN_ALGORITHM: u64 = 0x01_0001;
+
+/// Abort code when a value cannot be BCS-serialized (0x01 == INVALID_ARGUMENT)
+const ESERIALIZATION_FAILURE: u64 = 0x01_0002;
+
+/// Abort code when a hash is not 32 bytes long (0x01 == INVALID_ARGUMENT)
+/// NOTE: This must match the code in the Move implementation
+const EINVALID_HASH_LENGTH: u64 = 0x01_0003;
+
+/// The hash algorithms that can be selected by the `algorithm: u8` argument of some natives.
+/// NOTE: These must match the constants in the Move implementation
+pub mod algorithms {
+    pub const SHA3_256: u8 = 0;
+    pub const KECCAK_256: u8 = 1;
+    pub const SHA2_256: u8 = 2;
+}
+
+/// Returns whether `algorithm` is one of the `algorithms` above.
+fn is_known_algorithm(algorithm: u8) -> bool {
+    matches!(
+        algorithm,
+        algorithms::SHA3_256 | algorithms::KECCAK_256 | algorithms::SHA2_256
+    )
+}
+
+/// Hashes `bytes` with the given algorithm, or returns `None` if the algorithm is unknown.
+fn digest(algorithm: u8, bytes: &[u8]) -> Option<Vec<u8>> {
+    match algorithm {
+        algorithms::SHA3_256 => Some(Sha3_256::digest(bytes).to_vec()),
+        algorithms::KECCAK_256 => Some(Keccak256::digest(bytes).to_vec()),
+        algorithms::SHA2_256 => Some(Sha256::digest(bytes).to_vec()),
+        _ => None,
+    }
+}
+
+/// Concatenates the two children of a Merkle tree node in lexicographic (sorted) order, i.e., the
+/// smaller one first.
+fn concat_sorted(a: Vec<u8>, b: Vec<u8>) -> Vec<u8> {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut input = first;
+    input.extend(second);
+    input
+}
+
 /***************************************************************************************************
  * native fun sip_hash
  *
@@ -50,6 +111,366 @@ pub fn make_native_sip_hash(gas_params: SipHashGasParameters) -> NativeFunction
     Arc::new(move |context, ty_args, args| native_sip_hash(&gas_params, context, ty_args, args))
 }
 
+/***************************************************************************************************
+ * native fun sha256d
+ *
+ *   gas cost: base_cost + per_byte_cost * data_length
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct Sha256dGasParameters {
+    pub base_cost: InternalGas,
+    pub per_byte_cost: InternalGasPerByte,
+}
+
+fn native_sha256d(
+    gas_params: &Sha256dGasParameters,
+    _context: &mut NativeContext,
+    mut _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, Vec<u8>);
+
+    // The second hash is over a fixed 32-byte digest, so it is covered by the base cost.
+    let cost = gas_params.base_cost + gas_params.per_byte_cost * NumBytes::new(bytes.len() as u64);
+
+    let hash = Sha256::digest(&Sha256::digest(&bytes)).to_vec();
+
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(hash)]))
+}
+
+pub fn make_native_sha256d(gas_params: Sha256dGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_sha256d(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun eth_selector
+ *
+ *   gas cost: base_cost + per_byte_cost * data_length
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct EthSelectorGasParameters {
+    pub base_cost: InternalGas,
+    pub per_byte_cost: InternalGasPerByte,
+}
+
+/// Returns the first 4 bytes of the Keccak-256 hash of a Solidity function signature.
+fn native_eth_selector(
+    gas_params: &EthSelectorGasParameters,
+    _context: &mut NativeContext,
+    mut _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let signature = pop_arg!(args, Vec<u8>);
+
+    let cost =
+        gas_params.base_cost + gas_params.per_byte_cost * NumBytes::new(signature.len() as u64);
+
+    let selector = Keccak256::digest(&signature)[..4].to_vec();
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(selector)],
+    ))
+}
+
+pub fn make_native_eth_selector(gas_params: EthSelectorGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_eth_selector(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun crc32, crc32c
+ *
+ *   gas cost: base_cost + per_byte_cost * data_length
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct Crc32GasParameters {
+    pub base_cost: InternalGas,
+    pub per_byte_cost: InternalGasPerByte,
+}
+
+/// CRC-32 as used by zlib, gzip and PNG.
+static CRC32: Crc<u32> = Crc::<u32>::new(&CRC_32_ISO_HDLC);
+/// CRC-32C (Castagnoli) as used by iSCSI, ext4 and gRPC.
+static CRC32C: Crc<u32> = Crc::<u32>::new(&CRC_32_ISCSI);
+
+fn native_crc32(
+    gas_params: &Crc32GasParameters,
+    crc: &'static Crc<u32>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, Vec<u8>);
+
+    let cost = gas_params.base_cost + gas_params.per_byte_cost * NumBytes::new(bytes.len() as u64);
+
+    let checksum = crc.checksum(&bytes) as u64;
+
+    Ok(NativeResult::ok(cost, smallvec![Value::u64(checksum)]))
+}
+
+pub fn make_native_crc32(gas_params: Crc32GasParameters) -> NativeFunction {
+    Arc::new(move |_context, _ty_args, args| native_crc32(&gas_params, &CRC32, args))
+}
+
+pub fn make_native_crc32c(gas_params: Crc32GasParameters) -> NativeFunction {
+    Arc::new(move |_context, _ty_args, args| native_crc32(&gas_params, &CRC32C, args))
+}
+
+/***************************************************************************************************
+ * native fun hash_value
+ *
+ *   gas cost: base_cost + (per_byte_serialize_cost + per_byte_hash_cost) * serialized_length
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct HashValueGasParameters {
+    pub base_cost: InternalGas,
+    pub per_byte_serialize_cost: InternalGasPerByte,
+    pub per_byte_hash_cost: InternalGasPerByte,
+}
+
+/// BCS-serializes the referenced value and hashes the result with the selected algorithm.
+fn native_hash_value(
+    gas_params: &HashValueGasParameters,
+    context: &mut NativeContext,
+    mut ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.len() == 1);
+    debug_assert!(args.len() == 2);
+
+    let algorithm = pop_arg!(args, u8);
+    let ref_to_val = pop_arg!(args, Reference);
+    let ty = ty_args.pop().unwrap();
+
+    let mut cost = gas_params.base_cost;
+
+    // TODO(Gas): charge for getting the layout
+    let layout = context.type_to_type_layout(&ty)?.ok_or_else(|| {
+        PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR).with_message(format!(
+            "Failed to get layout of type {:?} -- this should not happen",
+            ty
+        ))
+    })?;
+
+    let bytes = match ref_to_val.read_ref()?.simple_serialize(&layout) {
+        Some(bytes) => bytes,
+        None => return Ok(NativeResult::err(cost, ESERIALIZATION_FAILURE)),
+    };
+
+    cost += (gas_params.per_byte_serialize_cost + gas_params.per_byte_hash_cost)
+        * NumBytes::new(bytes.len() as u64);
+
+    match digest(algorithm, &bytes) {
+        Some(hash) => Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(hash)])),
+        None => Ok(NativeResult::err(cost, EUNKNOWN_ALGORITHM)),
+    }
+}
+
+pub fn make_native_hash_value(gas_params: HashValueGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_hash_value(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun verify_merkle_proof
+ *
+ *   gas cost: base_cost + (per_level_cost + per_byte_hash_cost * level_input_length) * proof_length
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct VerifyMerkleProofGasParameters {
+    pub base_cost: InternalGas,
+    pub per_level_cost: InternalGasPerArg,
+    pub per_byte_hash_cost: InternalGasPerByte,
+}
+
+/// Walks a Merkle proof from `leaf` up to the root. At each level, the current node and the next
+/// sibling in the proof are concatenated in lexicographic (sorted) order and hashed with the
+/// selected algorithm. The leaf is used as is, so callers are responsible for hashing their data
+/// into a leaf first.
+fn native_verify_merkle_proof(
+    gas_params: &VerifyMerkleProofGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 4);
+
+    let algorithm = pop_arg!(args, u8);
+    let root = pop_arg!(args, Vec<u8>);
+    let proof = pop_vec_arg!(args, Vec<u8>);
+    let leaf = pop_arg!(args, Vec<u8>);
+
+    let mut cost = gas_params.base_cost;
+
+    if !is_known_algorithm(algorithm) {
+        return Ok(NativeResult::err(cost, EUNKNOWN_ALGORITHM));
+    }
+
+    let mut node = leaf;
+    for sibling in proof {
+        let input = concat_sorted(node, sibling);
+
+        cost += gas_params.per_level_cost * NumArgs::one()
+            + gas_params.per_byte_hash_cost * NumBytes::new(input.len() as u64);
+
+        node = match digest(algorithm, &input) {
+            Some(hash) => hash,
+            None => return Ok(NativeResult::err(cost, EUNKNOWN_ALGORITHM)),
+        };
+    }
+
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(node == root)]))
+}
+
+pub fn make_native_verify_merkle_proof(
+    gas_params: VerifyMerkleProofGasParameters,
+) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        native_verify_merkle_proof(&gas_params, context, ty_args, args)
+    })
+}
+
+/***************************************************************************************************
+ * native fun merkle_node_sorted
+ *
+ *   gas cost: base_cost + per_byte_hash_cost * (a_length + b_length)
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct MerkleNodeSortedGasParameters {
+    pub base_cost: InternalGas,
+    pub per_byte_hash_cost: InternalGasPerByte,
+}
+
+/// Hashes the two children of a Merkle tree node, concatenated in sorted order as in
+/// `verify_merkle_proof`, with the selected algorithm.
+fn native_merkle_node_sorted(
+    gas_params: &MerkleNodeSortedGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 3);
+
+    let algorithm = pop_arg!(args, u8);
+    let b = pop_arg!(args, Vec<u8>);
+    let a = pop_arg!(args, Vec<u8>);
+
+    let input = concat_sorted(a, b);
+    let cost =
+        gas_params.base_cost + gas_params.per_byte_hash_cost * NumBytes::new(input.len() as u64);
+
+    match digest(algorithm, &input) {
+        Some(hash) => Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(hash)])),
+        None => Ok(NativeResult::err(cost, EUNKNOWN_ALGORITHM)),
+    }
+}
+
+pub fn make_native_merkle_node_sorted(gas_params: MerkleNodeSortedGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        native_merkle_node_sorted(&gas_params, context, ty_args, args)
+    })
+}
+
+/***************************************************************************************************
+ * native fun accumulator_leaf_hash
+ *
+ *   gas cost: base_cost + per_byte_cost * data_length
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct AccumulatorLeafHashGasParameters {
+    pub base_cost: InternalGas,
+    pub per_byte_cost: InternalGasPerByte,
+}
+
+/// Hashes BCS-serialized `TransactionInfo` bytes the way the node computes the leaves of the
+/// transaction accumulator.
+fn native_accumulator_leaf_hash(
+    gas_params: &AccumulatorLeafHashGasParameters,
+    _context: &mut NativeContext,
+    mut _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 1);
+
+    let bytes = pop_arg!(args, Vec<u8>);
+
+    let cost = gas_params.base_cost + gas_params.per_byte_cost * NumBytes::new(bytes.len() as u64);
+
+    let hash = TransactionInfoHasher::hash_all(&bytes).to_vec();
+
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(hash)]))
+}
+
+pub fn make_native_accumulator_leaf_hash(
+    gas_params: AccumulatorLeafHashGasParameters,
+) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        native_accumulator_leaf_hash(&gas_params, context, ty_args, args)
+    })
+}
+
+/***************************************************************************************************
+ * native fun accumulator_internal_hash
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct AccumulatorInternalHashGasParameters {
+    pub base_cost: InternalGas,
+}
+
+/// Hashes two children the way the node computes the internal nodes of the transaction
+/// accumulator.
+fn native_accumulator_internal_hash(
+    gas_params: &AccumulatorInternalHashGasParameters,
+    _context: &mut NativeContext,
+    mut _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert!(args.len() == 2);
+
+    let right = pop_arg!(args, Vec<u8>);
+    let left = pop_arg!(args, Vec<u8>);
+
+    let cost = gas_params.base_cost;
+
+    let (left, right) = match (HashValue::from_slice(&left), HashValue::from_slice(&right)) {
+        (Ok(left), Ok(right)) => (left, right),
+        _ => return Ok(NativeResult::err(cost, EINVALID_HASH_LENGTH)),
+    };
+    let hash = TransactionAccumulatorInternalNode::new(left, right)
+        .hash()
+        .to_vec();
+
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(hash)]))
+}
+
+pub fn make_native_accumulator_internal_hash(
+    gas_params: AccumulatorInternalHashGasParameters,
+) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        native_accumulator_internal_hash(&gas_params, context, ty_args, args)
+    })
+}
+
 /***************************************************************************************************
  * module
  *
@@ -57,10 +478,45 @@ pub fn make_native_sip_hash(gas_params: SipHashGasParameters) -> NativeFunction
 #[derive(Debug, Clone)]
 pub struct GasParameters {
     pub sip_hash: SipHashGasParameters,
+    pub sha256d: Sha256dGasParameters,
+    pub eth_selector: EthSelectorGasParameters,
+    pub crc32: Crc32GasParameters,
+    pub crc32c: Crc32GasParameters,
+    pub hash_value: HashValueGasParameters,
+    pub verify_merkle_proof: VerifyMerkleProofGasParameters,
+    pub merkle_node_sorted: MerkleNodeSortedGasParameters,
+    pub accumulator_leaf_hash: AccumulatorLeafHashGasParameters,
+    pub accumulator_internal_hash: AccumulatorInternalHashGasParameters,
 }
 
 pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
-    let natives = [("sip_hash", make_native_sip_hash(gas_params.sip_hash))];
+    let natives = [
+        ("sip_hash", make_native_sip_hash(gas_params.sip_hash)),
+        ("sha256d", make_native_sha256d(gas_params.sha256d)),
+        (
+            "eth_selector",
+            make_native_eth_selector(gas_params.eth_selector),
+        ),
+        ("crc32", make_native_crc32(gas_params.crc32)),
+        ("crc32c", make_native_crc32c(gas_params.crc32c)),
+        ("hash_value", make_native_hash_value(gas_params.hash_value)),
+        (
+            "verify_merkle_proof",
+            make_native_verify_merkle_proof(gas_params.verify_merkle_proof),
+        ),
+        (
+            "merkle_node_sorted",
+            make_native_merkle_node_sorted(gas_params.merkle_node_sorted),
+        ),
+        (
+            "accumulator_leaf_hash",
+            make_native_accumulator_leaf_hash(gas_params.accumulator_leaf_hash),
+        ),
+        (
+            "accumulator_internal_hash",
+            make_native_accumulator_internal_hash(gas_params.accumulator_internal_hash),
+        ),
+    ];
 
     crate::natives::helpers::make_module_natives(natives)
 }
@@ -29,7 +29,9 @@ pub struct GasParameters {
     pub account: account::GasParameters,
     pub ed25519: ed25519::GasParameters,
     pub bls12381: cryptography::bls12381::GasParameters,
+    pub bn254: cryptography::bn254::GasParameters,
     pub secp256k1: cryptography::secp256k1::GasParameters,
+    pub groth16: cryptography::groth16::GasParameters,
     pub hash: hash::GasParameters,
     pub type_info: type_info::GasParameters,
     pub util: util::GasParameters,
@@ -45,9 +47,25 @@ impl GasParameters {
                 create_address: account::CreateAddressGasParameters {
                     base_cost: 0.into(),
                 },
+                create_object_address: account::CreateObjectAddressGasParameters {
+                    base_cost: 0.into(),
+                    per_byte_cost: 0.into(),
+                },
                 create_signer: account::CreateSignerGasParameters {
                     base_cost: 0.into(),
                 },
+                create_signers: account::CreateSignersGasParameters {
+                    base_cost: 0.into(),
+                    per_signer_cost: 0.into(),
+                },
+                auth_key_from_ed25519_pubkey: account::AuthKeyFromPubkeyGasParameters {
+                    base_cost: 0.into(),
+                    per_byte_cost: 0.into(),
+                },
+                auth_key_from_multi_ed25519_pubkey: account::AuthKeyFromPubkeyGasParameters {
+                    base_cost: 0.into(),
+                    per_byte_cost: 0.into(),
+                },
             },
             bls12381: cryptography::bls12381::GasParameters {
                 base_cost: 0.into(),
@@ -62,6 +80,28 @@ impl GasParameters {
                 per_pairing_cost: 0.into(),
                 per_msg_hashing_cost: 0.into(),
                 per_byte_hashing_cost: 0.into(),
+                per_distinct_msg_check_cost: 0.into(),
+                per_distinct_msg_byte_check_cost: 0.into(),
+            },
+            bn254: cryptography::bn254::GasParameters {
+                g1_add: cryptography::bn254::G1AddGasParameters {
+                    base_cost: 0.into(),
+                },
+                g1_mul: cryptography::bn254::G1MulGasParameters {
+                    base_cost: 0.into(),
+                },
+                g2_add: cryptography::bn254::G2AddGasParameters {
+                    base_cost: 0.into(),
+                },
+                g2_mul: cryptography::bn254::G2MulGasParameters {
+                    base_cost: 0.into(),
+                },
+                pairing_check: cryptography::bn254::PairingCheckGasParameters {
+                    base_cost: 0.into(),
+                    per_g1_deserialize_cost: 0.into(),
+                    per_g2_deserialize_cost: 0.into(),
+                    per_pairing_cost: 0.into(),
+                },
             },
             ed25519: cryptography::ed25519::GasParameters {
                 base_cost: 0.into(),
@@ -75,12 +115,58 @@ impl GasParameters {
             secp256k1: cryptography::secp256k1::GasParameters {
                 base_cost: 0.into(),
                 ecdsa_recover_cost: 0.into(),
+                eth_address_cost: 0.into(),
+            },
+            groth16: cryptography::groth16::GasParameters {
+                base_cost: 0.into(),
+                per_vk_deserialize_cost: 0.into(),
+                per_vk_point_deserialize_cost: 0.into(),
+                per_proof_deserialize_cost: 0.into(),
+                per_public_input_cost: 0.into(),
+                per_pairing_cost: 0.into(),
             },
             hash: hash::GasParameters {
                 sip_hash: hash::SipHashGasParameters {
                     base_cost: 0.into(),
                     unit_cost: 0.into(),
                 },
+                sha256d: hash::Sha256dGasParameters {
+                    base_cost: 0.into(),
+                    per_byte_cost: 0.into(),
+                },
+                eth_selector: hash::EthSelectorGasParameters {
+                    base_cost: 0.into(),
+                    per_byte_cost: 0.into(),
+                },
+                crc32: hash::Crc32GasParameters {
+                    base_cost: 0.into(),
+                    per_byte_cost: 0.into(),
+                },
+                crc32c: hash::Crc32GasParameters {
+                    base_cost: 0.into(),
+                    per_byte_cost: 0.into(),
+                },
+                hash_value: hash::HashValueGasParameters {
+                    base_cost: 0.into(),
+                    per_byte_serialize_cost: 0.into(),
+                    per_byte_hash_cost: 0.into(),
+                },
+                verify_merkle_proof: hash::VerifyMerkleProofGasParameters {
+                    base_cost: 0.into(),
+                    per_level_cost: 0.into(),
+                    per_byte_hash_cost: 0.into(),
+                },
+                merkle_node_sorted: hash::MerkleNodeSortedGasParameters {
+                    base_cost: 0.into(),
+                    per_byte_hash_cost: 0.into(),
+                },
+                accumulator_leaf_hash: hash::AccumulatorLeafHashGasParameters {
+                    base_cost: 0.into(),
+                    per_byte_cost: 0.into(),
+                },
+                accumulator_internal_hash: hash::AccumulatorInternalHashGasParameters {
+                    base_cost: 0.into(),
+                },
             },
             type_info: type_info::GasParameters {
                 type_of: type_info::TypeOfGasParameters {
@@ -91,23 +177,113 @@ impl GasParameters {
                     base_cost: 0.into(),
                     unit_cost: 0.into(),
                 },
+                parse_type_tag: type_info::ParseTypeTagGasParameters {
+                    base_cost: 0.into(),
+                    per_byte_cost: 0.into(),
+                },
             },
             util: util::GasParameters {
                 from_bytes: util::FromBytesGasParameters {
                     base_cost: 0.into(),
                     unit_cost: 0.into(),
                 },
+                bytes_equal_constant_time: util::ConstantTimeEqGasParameters {
+                    base_cost: 0.into(),
+                    per_byte_cost: 0.into(),
+                },
+                ct_select: util::ConstantTimeSelectGasParameters {
+                    base_cost: 0.into(),
+                    per_byte_cost: 0.into(),
+                },
+                to_hex: util::ToHexGasParameters {
+                    base_cost: 0.into(),
+                    per_byte_cost: 0.into(),
+                },
+                from_hex: util::FromHexGasParameters {
+                    base_cost: 0.into(),
+                    per_byte_cost: 0.into(),
+                },
+                base64_encode: util::Base64EncodeGasParameters {
+                    base_cost: 0.into(),
+                    per_byte_cost: 0.into(),
+                },
+                base64_decode: util::Base64DecodeGasParameters {
+                    base_cost: 0.into(),
+                    per_byte_cost: 0.into(),
+                },
+                reverse_bytes: util::ReverseBytesGasParameters {
+                    base_cost: 0.into(),
+                    per_byte_cost: 0.into(),
+                },
+                u64_to_bytes: util::U64ToBytesGasParameters {
+                    base_cost: 0.into(),
+                },
+                u64_from_bytes: util::U64FromBytesGasParameters {
+                    base_cost: 0.into(),
+                },
+                mul_u128_wide: util::MulWideGasParameters {
+                    base_cost: 0.into(),
+                },
+                mod_inverse: util::ModInverseGasParameters {
+                    base_cost: 0.into(),
+                    per_bit_cost: 0.into(),
+                },
+                mul_div: util::MulDivGasParameters {
+                    base_cost: 0.into(),
+                },
+                bytes_slice: util::BytesSliceGasParameters {
+                    base_cost: 0.into(),
+                    per_byte_cost: 0.into(),
+                },
+                bytes_concat: util::BytesConcatGasParameters {
+                    base_cost: 0.into(),
+                    per_byte_cost: 0.into(),
+                },
+                rotate: util::RotateGasParameters {
+                    base_cost: 0.into(),
+                },
+                bit_count: util::BitCountGasParameters {
+                    base_cost: 0.into(),
+                },
+                shuffle_in_place: util::ShuffleGasParameters {
+                    base_cost: 0.into(),
+                    per_element_cost: 0.into(),
+                },
+                random_bytes: util::RandomBytesGasParameters {
+                    base_cost: 0.into(),
+                    per_byte_cost: 0.into(),
+                },
+                commit_vec: util::CommitVecGasParameters {
+                    base_cost: 0.into(),
+                    per_item_cost: 0.into(),
+                    per_byte_cost: 0.into(),
+                },
+                sort: util::SortGasParameters {
+                    base_cost: 0.into(),
+                    per_comparison_cost: 0.into(),
+                },
             },
             transaction_context: transaction_context::GasParameters {
                 get_script_hash: transaction_context::GetScriptHashGasParameters {
                     base_cost: 0.into(),
                 },
+                fresh_id: transaction_context::FreshIdGasParameters {
+                    base_cost: 0.into(),
+                },
             },
             code: code::GasParameters {
                 request_publish: code::RequestPublishGasParameters {
                     base_cost: 0.into(),
                     unit_cost: 0.into(),
                 },
+                module_friends: code::ModuleFriendsGasParameters {
+                    base_cost: 0.into(),
+                    per_byte_cost: 0.into(),
+                },
+                module_bytecode_version: code::BytecodeVersionGasParameters {
+                    base_cost: 0.into(),
+                    per_byte_cost: 0.into(),
+                },
             },
             event: event::GasParameters {
                 write_to_event_store: event::WriteToEventStoreGasParameters {
@@ -139,10 +315,15 @@ pub fn all_natives(
         "bls12381",
         cryptography::bls12381::make_all(gas_params.bls12381)
     );
+    add_natives_from_module!("bn254", cryptography::bn254::make_all(gas_params.bn254));
     add_natives_from_module!(
         "secp256k1",
         cryptography::secp256k1::make_all(gas_params.secp256k1)
     );
+    add_natives_from_module!(
+        "groth16",
+        cryptography::groth16::make_all(gas_params.groth16)
+    );
     add_natives_from_module!("aptos_hash", hash::make_all(gas_params.hash));
     add_natives_from_module!("type_info", type_info::make_all(gas_params.type_info));
     add_natives_from_module!("util", util::make_all(gas_params.util));
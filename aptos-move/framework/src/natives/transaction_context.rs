@@ -20,13 +20,18 @@ use std::sync::Arc;
 #[derive(Tid)]
 pub struct NativeTransactionContext {
     script_hash: Vec<u8>,
+    /// The next ID to be returned by `fresh_id`.
+    next_fresh_id: u64,
 }
 
 impl NativeTransactionContext {
     /// Create a new instance of a native transaction context. This must be passed in via an
     /// extension into VM session functions.
     pub fn new(script_hash: Vec<u8>) -> Self {
-        Self { script_hash }
+        Self {
+            script_hash,
+            next_fresh_id: 0,
+        }
     }
 }
 
@@ -61,6 +66,40 @@ pub fn make_native_get_script_hash(gas_params: GetScriptHashGasParameters) -> Na
     })
 }
 
+/***************************************************************************************************
+ * native fun fresh_id
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Clone, Debug)]
+pub struct FreshIdGasParameters {
+    pub base_cost: InternalGas,
+}
+
+fn native_fresh_id(
+    gas_params: &FreshIdGasParameters,
+    context: &mut NativeContext,
+    mut _ty_args: Vec<Type>,
+    _args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    let transaction_context = context
+        .extensions_mut()
+        .get_mut::<NativeTransactionContext>();
+
+    let id = transaction_context.next_fresh_id;
+    transaction_context.next_fresh_id += 1;
+
+    Ok(NativeResult::ok(
+        gas_params.base_cost,
+        smallvec![Value::u64(id)],
+    ))
+}
+
+pub fn make_native_fresh_id(gas_params: FreshIdGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_fresh_id(&gas_params, context, ty_args, args))
+}
+
 /***************************************************************************************************
  * module
  *
@@ -68,13 +107,17 @@ pub fn make_native_get_script_hash(gas_params: GetScriptHashGasParameters) -> Na
 #[derive(Debug, Clone)]
 pub struct GasParameters {
     pub get_script_hash: GetScriptHashGasParameters,
+    pub fresh_id: FreshIdGasParameters,
 }
 
 pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
-    let natives = [(
-        "get_script_hash",
-        make_native_get_script_hash(gas_params.get_script_hash),
-    )];
+    let natives = [
+        (
+            "get_script_hash",
+            make_native_get_script_hash(gas_params.get_script_hash),
+        ),
+        ("fresh_id", make_native_fresh_id(gas_params.fresh_id)),
+    ];
 
     crate::natives::helpers::make_module_natives(natives)
 }
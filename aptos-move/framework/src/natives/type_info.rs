@@ -2,15 +2,20 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use move_deps::{
-    move_binary_format::errors::PartialVMResult,
+    move_binary_format::errors::{PartialVMError, PartialVMResult},
     move_core_types::{
-        gas_algebra::{InternalGas, InternalGasPerAbstractMemoryUnit},
+        gas_algebra::{
+            InternalGas, InternalGasPerAbstractMemoryUnit, InternalGasPerByte, NumBytes,
+        },
         language_storage::{StructTag, TypeTag},
+        parser::parse_type_tag,
+        vm_status::StatusCode,
     },
     move_vm_runtime::native_functions::{NativeContext, NativeFunction},
     move_vm_types::{
         loaded_data::runtime_types::Type,
         natives::function::NativeResult,
+        pop_arg,
         values::{Struct, Value},
     },
 };
@@ -128,6 +133,86 @@ pub fn make_native_type_name(gas_params: TypeNameGasParameters) -> NativeFunctio
     Arc::new(move |context, ty_args, args| native_type_name(&gas_params, context, ty_args, args))
 }
 
+/***************************************************************************************************
+ * native fun parse_type_tag
+ *
+ *   Parses a type string, e.g., as returned by type_name, and returns the BCS bytes of its TypeTag.
+ *
+ *   gas cost: base_cost + per_byte_cost * string_len
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct ParseTypeTagGasParameters {
+    pub base_cost: InternalGas,
+    pub per_byte_cost: InternalGasPerByte,
+}
+
+/// The maximum nesting of type arguments accepted by `parse_type_tag`, e.g., 2 for
+/// `vector<vector<u8>>`. The parser recurses once per level, so this bounds its stack usage.
+const MAX_TYPE_TAG_NESTING: usize = 8;
+
+/// Returns whether the `<`/`>` nesting in `type_string` stays within `MAX_TYPE_TAG_NESTING`.
+fn type_tag_nesting_within_limit(type_string: &[u8]) -> bool {
+    let mut depth = 0usize;
+    for byte in type_string {
+        match byte {
+            b'<' => {
+                depth += 1;
+                if depth > MAX_TYPE_TAG_NESTING {
+                    return false;
+                }
+            }
+            b'>' => depth = depth.saturating_sub(1),
+            _ => (),
+        }
+    }
+    true
+}
+
+fn native_parse_type_tag(
+    gas_params: &ParseTypeTagGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut arguments: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(ty_args.is_empty());
+    debug_assert!(arguments.len() == 1);
+
+    let type_string = pop_arg!(arguments, Struct)
+        .unpack()?
+        .next()
+        .ok_or_else(|| PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR))?
+        .value_as::<Vec<u8>>()?;
+
+    let cost =
+        gas_params.base_cost + gas_params.per_byte_cost * NumBytes::new(type_string.len() as u64);
+
+    if !type_tag_nesting_within_limit(&type_string) {
+        return Ok(NativeResult::err(
+            cost,
+            super::status::NFE_EXPECTED_STRUCT_TYPE_TAG,
+        ));
+    }
+
+    match std::str::from_utf8(&type_string)
+        .ok()
+        .and_then(|s| parse_type_tag(s).ok())
+        .and_then(|type_tag| bcs::to_bytes(&type_tag).ok())
+    {
+        Some(bytes) => Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(bytes)])),
+        None => Ok(NativeResult::err(
+            cost,
+            super::status::NFE_EXPECTED_STRUCT_TYPE_TAG,
+        )),
+    }
+}
+
+pub fn make_native_parse_type_tag(gas_params: ParseTypeTagGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        native_parse_type_tag(&gas_params, context, ty_args, args)
+    })
+}
+
 /***************************************************************************************************
  * module
  *
@@ -136,12 +221,17 @@ pub fn make_native_type_name(gas_params: TypeNameGasParameters) -> NativeFunctio
 pub struct GasParameters {
     pub type_of: TypeOfGasParameters,
     pub type_name: TypeNameGasParameters,
+    pub parse_type_tag: ParseTypeTagGasParameters,
 }
 
 pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
     let natives = [
         ("type_of", make_native_type_of(gas_params.type_of)),
         ("type_name", make_native_type_name(gas_params.type_name)),
+        (
+            "parse_type_tag",
+            make_native_parse_type_tag(gas_params.parse_type_tag),
+        ),
     ];
 
     crate::natives::helpers::make_module_natives(natives)
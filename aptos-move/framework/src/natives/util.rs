@@ -4,20 +4,69 @@
 use move_deps::{
     move_binary_format::errors::{PartialVMError, PartialVMResult},
     move_core_types::{
-        gas_algebra::{InternalGas, InternalGasPerByte, NumBytes},
+        gas_algebra::{InternalGas, InternalGasPerArg, InternalGasPerByte, NumArgs, NumBytes},
+        value::MoveValue,
         vm_status::StatusCode,
     },
     move_vm_runtime::native_functions::{NativeContext, NativeFunction},
     move_vm_types::{
-        loaded_data::runtime_types::Type, natives::function::NativeResult, pop_arg, values::Value,
+        loaded_data::runtime_types::Type,
+        natives::function::NativeResult,
+        pop_arg,
+        values::{Struct, Value, VectorRef},
     },
 };
+use rand_chacha::{
+    rand_core::{RngCore, SeedableRng},
+    ChaCha20Rng,
+};
+use sha3::{Digest, Sha3_256};
 use smallvec::smallvec;
-use std::{collections::VecDeque, sync::Arc};
+use std::{collections::VecDeque, convert::TryFrom, sync::Arc};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 /// Abort code when from_bytes fails (0x01 == INVALID_ARGUMENT)
 const EFROM_BYTES: u64 = 0x01_0001;
 
+/// Abort code when from_hex is given a string that is not valid hex (0x01 == INVALID_ARGUMENT)
+/// NOTE: This must match the code in the Move implementation
+const EINVALID_HEX: u64 = 0x01_0002;
+
+/// Abort code when base64_decode is given a string that is not valid base64 (0x01 == INVALID_ARGUMENT)
+/// NOTE: This must match the code in the Move implementation
+const EINVALID_BASE64: u64 = 0x01_0003;
+
+/// Abort code when u64_from_le_bytes or u64_from_be_bytes is not given exactly 8 bytes
+/// (0x01 == INVALID_ARGUMENT)
+/// NOTE: This must match the code in the Move implementation
+const EINVALID_U64_BYTES: u64 = 0x01_0004;
+
+/// Abort code when bytes_slice is given a range that is not within the vector (0x01 == INVALID_ARGUMENT)
+/// NOTE: This must match the code in the Move implementation
+const EINVALID_SLICE_RANGE: u64 = 0x01_0005;
+
+/// Abort code when ct_select is given byte vectors of different lengths (0x01 == INVALID_ARGUMENT)
+/// NOTE: This must match the code in the Move implementation
+const ELENGTH_MISMATCH: u64 = 0x01_0006;
+
+/// Abort code when commit_vec fails to serialize the vector (0x01 == INVALID_ARGUMENT)
+const ESERIALIZATION_FAILURE: u64 = 0x01_0007;
+
+/// Abort code when random_bytes is asked for more than MAX_RANDOM_BYTES bytes (0x01 == INVALID_ARGUMENT)
+/// NOTE: This must match the code in the Move implementation
+const ETOO_MANY_RANDOM_BYTES: u64 = 0x01_0008;
+
+/// Abort code when mul_div is given a zero denominator (0x01 == INVALID_ARGUMENT)
+/// NOTE: This must match the code in the Move implementation
+const EDIVISION_BY_ZERO: u64 = 0x01_0009;
+
+/// Abort code when the result of mul_div does not fit in a u128 (0x02 == OUT_OF_RANGE)
+/// NOTE: This must match the code in the Move implementation
+const EMUL_DIV_OVERFLOW: u64 = 0x02_000A;
+
+/// The maximum number of bytes that random_bytes can return in one call
+const MAX_RANDOM_BYTES: u64 = 1 << 16;
+
 /// Used to pass gas parameters into native functions.
 pub fn make_native_from_func<T: std::marker::Send + std::marker::Sync + 'static>(
     gas_params: T,
@@ -56,6 +105,20 @@ macro_rules! pop_vec_arg {
     }};
 }
 
+/// Pops a `std::string::String` off the argument stack and returns its UTF-8 bytes.
+fn pop_string_bytes(args: &mut VecDeque<Value>) -> PartialVMResult<Vec<u8>> {
+    pop_arg!(args, Struct)
+        .unpack()?
+        .next()
+        .ok_or_else(|| PartialVMError::new(StatusCode::INTERNAL_TYPE_ERROR))?
+        .value_as::<Vec<u8>>()
+}
+
+/// Wraps UTF-8 bytes into a `std::string::String` value.
+fn string_value(bytes: Vec<u8>) -> Value {
+    Value::struct_(Struct::pack(vec![Value::vector_u8(bytes)]))
+}
+
 /***************************************************************************************************
  * native fun from_bytes
  *
@@ -99,6 +162,978 @@ pub fn make_native_from_bytes(gas_params: FromBytesGasParameters) -> NativeFunct
     Arc::new(move |context, ty_args, args| native_from_bytes(&gas_params, context, ty_args, args))
 }
 
+/***************************************************************************************************
+ * native fun bytes_equal_constant_time
+ *
+ *   gas cost: base_cost + per_byte_cost * max(a_len, b_len)
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct ConstantTimeEqGasParameters {
+    pub base_cost: InternalGas,
+    pub per_byte_cost: InternalGasPerByte,
+}
+
+/// Compares two byte vectors in time that only depends on their lengths, not their contents.
+fn native_bytes_equal_constant_time(
+    gas_params: &ConstantTimeEqGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert_eq!(args.len(), 2);
+
+    let b = pop_arg!(args, Vec<u8>);
+    let a = pop_arg!(args, Vec<u8>);
+
+    let cost = gas_params.base_cost
+        + gas_params.per_byte_cost * NumBytes::new(a.len().max(b.len()) as u64);
+
+    let equal: bool = a.ct_eq(&b).into();
+
+    Ok(NativeResult::ok(cost, smallvec![Value::bool(equal)]))
+}
+
+pub fn make_native_bytes_equal_constant_time(
+    gas_params: ConstantTimeEqGasParameters,
+) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        native_bytes_equal_constant_time(&gas_params, context, ty_args, args)
+    })
+}
+
+/***************************************************************************************************
+ * native fun ct_select
+ *
+ *   gas cost: base_cost + per_byte_cost * max(a_len, b_len)
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct ConstantTimeSelectGasParameters {
+    pub base_cost: InternalGas,
+    pub per_byte_cost: InternalGasPerByte,
+}
+
+/// Returns `a` if `cond` is true and `b` otherwise, without branching on `cond`.
+fn native_ct_select(
+    gas_params: &ConstantTimeSelectGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert_eq!(args.len(), 3);
+
+    let b = pop_arg!(args, Vec<u8>);
+    let a = pop_arg!(args, Vec<u8>);
+    let cond = pop_arg!(args, bool);
+
+    let cost = gas_params.base_cost
+        + gas_params.per_byte_cost * NumBytes::new(a.len().max(b.len()) as u64);
+
+    if a.len() != b.len() {
+        return Ok(NativeResult::err(cost, ELENGTH_MISMATCH));
+    }
+
+    // `conditional_select(x, y, choice)` returns `y` when `choice` is set.
+    let choice = Choice::from(cond as u8);
+    let selected: Vec<u8> = a
+        .iter()
+        .zip(b.iter())
+        .map(|(a, b)| u8::conditional_select(b, a, choice))
+        .collect();
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(selected)],
+    ))
+}
+
+pub fn make_native_ct_select(gas_params: ConstantTimeSelectGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_ct_select(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun to_hex
+ *
+ *   gas cost: base_cost + per_byte_cost * bytes_len
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct ToHexGasParameters {
+    pub base_cost: InternalGas,
+    pub per_byte_cost: InternalGasPerByte,
+}
+
+/// Encodes bytes as a lowercase hex `std::string::String`, without a `0x` prefix.
+fn native_to_hex(
+    gas_params: &ToHexGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert_eq!(args.len(), 1);
+
+    let bytes = pop_arg!(args, Vec<u8>);
+
+    let cost = gas_params.base_cost + gas_params.per_byte_cost * NumBytes::new(bytes.len() as u64);
+
+    let hex_string = hex::encode(bytes).into_bytes();
+
+    Ok(NativeResult::ok(cost, smallvec![string_value(hex_string)]))
+}
+
+pub fn make_native_to_hex(gas_params: ToHexGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_to_hex(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun from_hex
+ *
+ *   gas cost: base_cost + per_byte_cost * string_len
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct FromHexGasParameters {
+    pub base_cost: InternalGas,
+    pub per_byte_cost: InternalGasPerByte,
+}
+
+/// Decodes a `std::string::String` of (lowercase or uppercase) hex digits, without a `0x` prefix.
+fn native_from_hex(
+    gas_params: &FromHexGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert_eq!(args.len(), 1);
+
+    let hex_string = pop_string_bytes(&mut args)?;
+
+    let cost =
+        gas_params.base_cost + gas_params.per_byte_cost * NumBytes::new(hex_string.len() as u64);
+
+    match hex::decode(hex_string) {
+        Ok(bytes) => Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(bytes)])),
+        Err(_) => Ok(NativeResult::err(cost, EINVALID_HEX)),
+    }
+}
+
+pub fn make_native_from_hex(gas_params: FromHexGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_from_hex(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun base64_encode
+ *
+ *   gas cost: base_cost + per_byte_cost * bytes_len
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct Base64EncodeGasParameters {
+    pub base_cost: InternalGas,
+    pub per_byte_cost: InternalGasPerByte,
+}
+
+/// Returns the standard or the URL-safe base64 alphabet, both with padding.
+fn base64_config(url_safe: bool) -> base64::Config {
+    if url_safe {
+        base64::URL_SAFE
+    } else {
+        base64::STANDARD
+    }
+}
+
+/// Encodes bytes as a padded base64 `std::string::String`.
+fn native_base64_encode(
+    gas_params: &Base64EncodeGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert_eq!(args.len(), 2);
+
+    let url_safe = pop_arg!(args, bool);
+    let bytes = pop_arg!(args, Vec<u8>);
+
+    let cost = gas_params.base_cost + gas_params.per_byte_cost * NumBytes::new(bytes.len() as u64);
+
+    let encoded = base64::encode_config(bytes, base64_config(url_safe)).into_bytes();
+
+    Ok(NativeResult::ok(cost, smallvec![string_value(encoded)]))
+}
+
+pub fn make_native_base64_encode(gas_params: Base64EncodeGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        native_base64_encode(&gas_params, context, ty_args, args)
+    })
+}
+
+/***************************************************************************************************
+ * native fun base64_decode
+ *
+ *   gas cost: base_cost + per_byte_cost * string_len
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct Base64DecodeGasParameters {
+    pub base_cost: InternalGas,
+    pub per_byte_cost: InternalGasPerByte,
+}
+
+/// Decodes a base64 `std::string::String`; the trailing padding is optional.
+fn native_base64_decode(
+    gas_params: &Base64DecodeGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert_eq!(args.len(), 2);
+
+    let url_safe = pop_arg!(args, bool);
+    let encoded = pop_string_bytes(&mut args)?;
+
+    let cost =
+        gas_params.base_cost + gas_params.per_byte_cost * NumBytes::new(encoded.len() as u64);
+
+    match base64::decode_config(encoded, base64_config(url_safe)) {
+        Ok(bytes) => Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(bytes)])),
+        Err(_) => Ok(NativeResult::err(cost, EINVALID_BASE64)),
+    }
+}
+
+pub fn make_native_base64_decode(gas_params: Base64DecodeGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        native_base64_decode(&gas_params, context, ty_args, args)
+    })
+}
+
+/***************************************************************************************************
+ * native fun reverse_bytes
+ *
+ *   gas cost: base_cost + per_byte_cost * bytes_len
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct ReverseBytesGasParameters {
+    pub base_cost: InternalGas,
+    pub per_byte_cost: InternalGasPerByte,
+}
+
+fn native_reverse_bytes(
+    gas_params: &ReverseBytesGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert_eq!(args.len(), 1);
+
+    let mut bytes = pop_arg!(args, Vec<u8>);
+
+    let cost = gas_params.base_cost + gas_params.per_byte_cost * NumBytes::new(bytes.len() as u64);
+
+    bytes.reverse();
+
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(bytes)]))
+}
+
+pub fn make_native_reverse_bytes(gas_params: ReverseBytesGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        native_reverse_bytes(&gas_params, context, ty_args, args)
+    })
+}
+
+/***************************************************************************************************
+ * native fun u64_to_le_bytes, u64_to_be_bytes
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct U64ToBytesGasParameters {
+    pub base_cost: InternalGas,
+}
+
+fn native_u64_to_bytes(
+    gas_params: &U64ToBytesGasParameters,
+    big_endian: bool,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert_eq!(args.len(), 1);
+
+    let n = pop_arg!(args, u64);
+
+    let bytes = if big_endian {
+        n.to_be_bytes()
+    } else {
+        n.to_le_bytes()
+    };
+
+    Ok(NativeResult::ok(
+        gas_params.base_cost,
+        smallvec![Value::vector_u8(bytes.to_vec())],
+    ))
+}
+
+pub fn make_native_u64_to_bytes(
+    gas_params: U64ToBytesGasParameters,
+    big_endian: bool,
+) -> NativeFunction {
+    Arc::new(move |_context, _ty_args, args| native_u64_to_bytes(&gas_params, big_endian, args))
+}
+
+/***************************************************************************************************
+ * native fun u64_from_le_bytes, u64_from_be_bytes
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct U64FromBytesGasParameters {
+    pub base_cost: InternalGas,
+}
+
+fn native_u64_from_bytes(
+    gas_params: &U64FromBytesGasParameters,
+    big_endian: bool,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert_eq!(args.len(), 1);
+
+    let bytes = pop_arg!(args, Vec<u8>);
+
+    let cost = gas_params.base_cost;
+
+    let bytes = match <[u8; 8]>::try_from(bytes.as_slice()) {
+        Ok(bytes) => bytes,
+        Err(_) => return Ok(NativeResult::err(cost, EINVALID_U64_BYTES)),
+    };
+    let n = if big_endian {
+        u64::from_be_bytes(bytes)
+    } else {
+        u64::from_le_bytes(bytes)
+    };
+
+    Ok(NativeResult::ok(cost, smallvec![Value::u64(n)]))
+}
+
+pub fn make_native_u64_from_bytes(
+    gas_params: U64FromBytesGasParameters,
+    big_endian: bool,
+) -> NativeFunction {
+    Arc::new(move |_context, _ty_args, args| native_u64_from_bytes(&gas_params, big_endian, args))
+}
+
+/***************************************************************************************************
+ * native fun mul_u128_wide
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct MulWideGasParameters {
+    pub base_cost: InternalGas,
+}
+
+/// Returns the high and the low 128 bits of the 256-bit product `a * b`.
+fn mul_u128_wide(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+
+    let (a_hi, a_lo) = (a >> 64, a & MASK);
+    let (b_hi, b_lo) = (b >> 64, b & MASK);
+
+    // Schoolbook multiplication on 64-bit limbs; none of the partial products below can overflow.
+    let lo_lo = a_lo * b_lo;
+    let lo_hi = a_lo * b_hi;
+    let hi_lo = a_hi * b_lo;
+    let hi_hi = a_hi * b_hi;
+
+    let mid = (lo_lo >> 64) + (lo_hi & MASK) + (hi_lo & MASK);
+    let lo = (lo_lo & MASK) | (mid << 64);
+    let hi = hi_hi + (lo_hi >> 64) + (hi_lo >> 64) + (mid >> 64);
+
+    (hi, lo)
+}
+
+fn native_mul_u128_wide(
+    gas_params: &MulWideGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert_eq!(args.len(), 2);
+
+    let b = pop_arg!(args, u128);
+    let a = pop_arg!(args, u128);
+
+    let (hi, lo) = mul_u128_wide(a, b);
+
+    Ok(NativeResult::ok(
+        gas_params.base_cost,
+        smallvec![Value::u128(hi), Value::u128(lo)],
+    ))
+}
+
+pub fn make_native_mul_u128_wide(gas_params: MulWideGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        native_mul_u128_wide(&gas_params, context, ty_args, args)
+    })
+}
+
+/***************************************************************************************************
+ * native fun mod_inverse
+ *
+ *   gas cost: base_cost + per_bit_cost * modulus_bits
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct ModInverseGasParameters {
+    pub base_cost: InternalGas,
+    pub per_bit_cost: InternalGasPerArg,
+}
+
+/// Returns the inverse of `a` modulo `modulus` using the extended Euclidean algorithm, or `None`
+/// if `gcd(a, modulus) != 1` or `modulus` is 0.
+fn mod_inverse(a: u128, modulus: u128) -> Option<u128> {
+    if modulus == 0 {
+        return None;
+    }
+
+    // The Bezout coefficients t_i alternate in sign and their magnitudes are bounded by
+    // `modulus`, so we track magnitudes along with the sign of `t0` to stay within u128.
+    let (mut r0, mut r1) = (modulus, a % modulus);
+    let (mut t0, mut t1) = (0u128, 1u128);
+    let mut t0_negative = true;
+    while r1 != 0 {
+        let q = r0 / r1;
+        (r0, r1) = (r1, r0 - q * r1);
+        (t0, t1) = (t1, t0 + q * t1);
+        t0_negative = !t0_negative;
+    }
+
+    if r0 != 1 {
+        return None;
+    }
+    if t0_negative && t0 != 0 {
+        Some(modulus - t0)
+    } else {
+        Some(t0 % modulus)
+    }
+}
+
+fn native_mod_inverse(
+    gas_params: &ModInverseGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert_eq!(args.len(), 2);
+
+    let modulus = pop_arg!(args, u128);
+    let a = pop_arg!(args, u128);
+
+    let modulus_bits = (u128::BITS - modulus.leading_zeros()) as u64;
+    let cost = gas_params.base_cost + gas_params.per_bit_cost * NumArgs::new(modulus_bits);
+
+    // An `Option<u128>` is a struct holding a vector of at most one element.
+    let inverse = mod_inverse(a, modulus);
+    let option = Value::struct_(Struct::pack(vec![Value::vector_u128(inverse)]));
+
+    Ok(NativeResult::ok(cost, smallvec![option]))
+}
+
+pub fn make_native_mod_inverse(gas_params: ModInverseGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_mod_inverse(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun mul_div
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct MulDivGasParameters {
+    pub base_cost: InternalGas,
+}
+
+/// Divides the 256-bit number `hi * 2^128 + lo` by `d`, returning the quotient and the remainder,
+/// or `None` if `d` is 0 or the quotient does not fit in 128 bits.
+fn div_u256_by_u128(hi: u128, lo: u128, d: u128) -> Option<(u128, u128)> {
+    // The quotient fits in 128 bits iff `hi < d`, which also rules out `d == 0`.
+    if hi >= d {
+        return None;
+    }
+
+    // Binary long division, shifting the bits of `lo` into the remainder one at a time. The
+    // remainder stays below `d`, but shifting it may carry out a 129th bit, in which case it is
+    // certainly at least `d` and the wrapping subtraction gives the right result.
+    let (mut q, mut r) = (0u128, hi);
+    for i in (0..u128::BITS).rev() {
+        let carry = r >> 127;
+        r = (r << 1) | ((lo >> i) & 1);
+        q <<= 1;
+        if carry == 1 || r >= d {
+            r = r.wrapping_sub(d);
+            q |= 1;
+        }
+    }
+
+    Some((q, r))
+}
+
+fn native_mul_div(
+    gas_params: &MulDivGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert_eq!(args.len(), 4);
+
+    let round_up = pop_arg!(args, bool);
+    let denominator = pop_arg!(args, u128);
+    let b = pop_arg!(args, u128);
+    let a = pop_arg!(args, u128);
+
+    if denominator == 0 {
+        return Ok(NativeResult::err(gas_params.base_cost, EDIVISION_BY_ZERO));
+    }
+
+    let (hi, lo) = mul_u128_wide(a, b);
+    let result = div_u256_by_u128(hi, lo, denominator).and_then(|(q, r)| {
+        if round_up && r != 0 {
+            q.checked_add(1)
+        } else {
+            Some(q)
+        }
+    });
+
+    match result {
+        Some(result) => Ok(NativeResult::ok(
+            gas_params.base_cost,
+            smallvec![Value::u128(result)],
+        )),
+        None => Ok(NativeResult::err(gas_params.base_cost, EMUL_DIV_OVERFLOW)),
+    }
+}
+
+pub fn make_native_mul_div(gas_params: MulDivGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_mul_div(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun bytes_slice
+ *
+ *   gas cost: base_cost + per_byte_cost * bytes_len
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct BytesSliceGasParameters {
+    pub base_cost: InternalGas,
+    pub per_byte_cost: InternalGasPerByte,
+}
+
+fn native_bytes_slice(
+    gas_params: &BytesSliceGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert_eq!(args.len(), 3);
+
+    let len = pop_arg!(args, u64);
+    let start = pop_arg!(args, u64);
+    let bytes = pop_arg!(args, Vec<u8>);
+
+    let cost = gas_params.base_cost + gas_params.per_byte_cost * NumBytes::new(bytes.len() as u64);
+
+    let end = match start.checked_add(len) {
+        Some(end) if end <= bytes.len() as u64 => end,
+        _ => return Ok(NativeResult::err(cost, EINVALID_SLICE_RANGE)),
+    };
+    let slice = bytes[start as usize..end as usize].to_vec();
+
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(slice)]))
+}
+
+pub fn make_native_bytes_slice(gas_params: BytesSliceGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_bytes_slice(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun bytes_concat
+ *
+ *   gas cost: base_cost + per_byte_cost * (a_len + b_len)
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct BytesConcatGasParameters {
+    pub base_cost: InternalGas,
+    pub per_byte_cost: InternalGasPerByte,
+}
+
+fn native_bytes_concat(
+    gas_params: &BytesConcatGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert_eq!(args.len(), 2);
+
+    let b = pop_arg!(args, Vec<u8>);
+    let mut a = pop_arg!(args, Vec<u8>);
+
+    let cost =
+        gas_params.base_cost + gas_params.per_byte_cost * NumBytes::new((a.len() + b.len()) as u64);
+
+    a.extend(b);
+
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(a)]))
+}
+
+pub fn make_native_bytes_concat(gas_params: BytesConcatGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_bytes_concat(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun rotl_u64, rotr_u64, rotl_u128, rotr_u128
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct RotateGasParameters {
+    pub base_cost: InternalGas,
+}
+
+/// Rotates `n` by `shift` bits; the shift is taken modulo the width of the integer type.
+fn native_rotate_u64(
+    gas_params: &RotateGasParameters,
+    left: bool,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert_eq!(args.len(), 2);
+
+    let shift = pop_arg!(args, u8);
+    let n = pop_arg!(args, u64);
+
+    let rotated = if left {
+        n.rotate_left(shift as u32)
+    } else {
+        n.rotate_right(shift as u32)
+    };
+
+    Ok(NativeResult::ok(
+        gas_params.base_cost,
+        smallvec![Value::u64(rotated)],
+    ))
+}
+
+pub fn make_native_rotate_u64(gas_params: RotateGasParameters, left: bool) -> NativeFunction {
+    Arc::new(move |_context, _ty_args, args| native_rotate_u64(&gas_params, left, args))
+}
+
+/// Rotates `n` by `shift` bits; the shift is taken modulo the width of the integer type.
+fn native_rotate_u128(
+    gas_params: &RotateGasParameters,
+    left: bool,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert_eq!(args.len(), 2);
+
+    let shift = pop_arg!(args, u8);
+    let n = pop_arg!(args, u128);
+
+    let rotated = if left {
+        n.rotate_left(shift as u32)
+    } else {
+        n.rotate_right(shift as u32)
+    };
+
+    Ok(NativeResult::ok(
+        gas_params.base_cost,
+        smallvec![Value::u128(rotated)],
+    ))
+}
+
+pub fn make_native_rotate_u128(gas_params: RotateGasParameters, left: bool) -> NativeFunction {
+    Arc::new(move |_context, _ty_args, args| native_rotate_u128(&gas_params, left, args))
+}
+
+/***************************************************************************************************
+ * native fun count_ones_u128, leading_zeros_u128, trailing_zeros_u128
+ *
+ *   gas cost: base_cost
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct BitCountGasParameters {
+    pub base_cost: InternalGas,
+}
+
+/// Counts bits of `n` with `count`, one of `u128::count_ones`, `u128::leading_zeros` or
+/// `u128::trailing_zeros`. The result is at most 128, so it fits in a u8.
+fn native_bit_count_u128(
+    gas_params: &BitCountGasParameters,
+    count: fn(u128) -> u32,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert_eq!(args.len(), 1);
+
+    let n = pop_arg!(args, u128);
+
+    Ok(NativeResult::ok(
+        gas_params.base_cost,
+        smallvec![Value::u8(count(n) as u8)],
+    ))
+}
+
+pub fn make_native_bit_count_u128(
+    gas_params: BitCountGasParameters,
+    count: fn(u128) -> u32,
+) -> NativeFunction {
+    Arc::new(move |_context, _ty_args, args| native_bit_count_u128(&gas_params, count, args))
+}
+
+/***************************************************************************************************
+ * native fun shuffle_in_place
+ *
+ *   gas cost: base_cost + per_element_cost * vector_len
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct ShuffleGasParameters {
+    pub base_cost: InternalGas,
+    pub per_element_cost: InternalGasPerArg,
+}
+
+/// Shuffles the vector with a Fisher-Yates shuffle driven by a ChaCha20 RNG seeded with
+/// `sha3_256(seed)`. For `i` from `len - 1` down to 1, draws `u64`s from the RNG until one is below
+/// the largest multiple of `i + 1`, and swaps the elements at `i` and at that `u64` modulo `i + 1`.
+fn native_shuffle_in_place(
+    gas_params: &ShuffleGasParameters,
+    _context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert_eq!(ty_args.len(), 1);
+    debug_assert_eq!(args.len(), 2);
+
+    let seed = pop_arg!(args, Vec<u8>);
+    let v = pop_arg!(args, VectorRef);
+
+    let len = v.len(&ty_args[0])?.value_as::<u64>()?;
+    let cost = gas_params.base_cost + gas_params.per_element_cost * NumArgs::new(len);
+
+    let mut rng = ChaCha20Rng::from_seed(Sha3_256::digest(&seed).into());
+    for i in (1..len).rev() {
+        let bound = i + 1;
+        // Rejection sampling, so that every index is equally likely
+        let zone = u64::MAX - u64::MAX % bound;
+        let j = loop {
+            let r = rng.next_u64();
+            if r < zone {
+                break r % bound;
+            }
+        };
+        v.swap(i as usize, j as usize, &ty_args[0])?;
+    }
+
+    Ok(NativeResult::ok(cost, smallvec![]))
+}
+
+pub fn make_native_shuffle_in_place(gas_params: ShuffleGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| {
+        native_shuffle_in_place(&gas_params, context, ty_args, args)
+    })
+}
+
+/***************************************************************************************************
+ * native fun random_bytes
+ *
+ *   gas cost: base_cost + per_byte_cost * n
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct RandomBytesGasParameters {
+    pub base_cost: InternalGas,
+    pub per_byte_cost: InternalGasPerByte,
+}
+
+/// Returns the first `n` bytes of the keystream of a ChaCha20 RNG seeded with `sha3_256(seed)`, as
+/// in `shuffle_in_place`.
+fn native_random_bytes(
+    gas_params: &RandomBytesGasParameters,
+    _context: &mut NativeContext,
+    _ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert!(_ty_args.is_empty());
+    debug_assert_eq!(args.len(), 2);
+
+    let n = pop_arg!(args, u64);
+    let seed = pop_arg!(args, Vec<u8>);
+
+    if n > MAX_RANDOM_BYTES {
+        return Ok(NativeResult::err(
+            gas_params.base_cost,
+            ETOO_MANY_RANDOM_BYTES,
+        ));
+    }
+    let cost = gas_params.base_cost + gas_params.per_byte_cost * NumBytes::new(n);
+
+    let mut rng = ChaCha20Rng::from_seed(Sha3_256::digest(&seed).into());
+    let mut bytes = vec![0u8; n as usize];
+    rng.fill_bytes(&mut bytes);
+
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u8(bytes)]))
+}
+
+pub fn make_native_random_bytes(gas_params: RandomBytesGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_random_bytes(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun commit_vec
+ *
+ *   gas cost: base_cost + per_item_cost * items_len + per_byte_cost * serialized_len
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct CommitVecGasParameters {
+    pub base_cost: InternalGas,
+    pub per_item_cost: InternalGasPerArg,
+    pub per_byte_cost: InternalGasPerByte,
+}
+
+/// Returns `sha3_256(le_u64(n) || le_u64(len(b_1)) || b_1 || ... || le_u64(len(b_n)) || b_n)`, where
+/// `b_i` is the BCS serialization of the i-th of the `n` items.
+fn native_commit_vec(
+    gas_params: &CommitVecGasParameters,
+    context: &mut NativeContext,
+    ty_args: Vec<Type>,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert_eq!(ty_args.len(), 1);
+    debug_assert_eq!(args.len(), 1);
+
+    let items = args.pop_back().unwrap();
+    let ty = Type::Vector(Box::new(ty_args[0].clone()));
+
+    // TODO(Gas): charge for getting the layout
+    let layout = context.type_to_type_layout(&ty)?.ok_or_else(|| {
+        PartialVMError::new(StatusCode::UNKNOWN_INVARIANT_VIOLATION_ERROR).with_message(format!(
+            "Failed to get layout of type {:?} -- this should not happen",
+            ty
+        ))
+    })?;
+
+    let mut cost = gas_params.base_cost;
+
+    // The BCS serialization of the vector is the concatenation of the serializations of its items,
+    // so the items are split apart by deserializing it.
+    let items = match items
+        .simple_serialize(&layout)
+        .and_then(|bytes| MoveValue::simple_deserialize(&bytes, &layout).ok())
+    {
+        Some(MoveValue::Vector(items)) => items,
+        _ => return Ok(NativeResult::err(cost, ESERIALIZATION_FAILURE)),
+    };
+
+    cost += gas_params.per_item_cost * NumArgs::new(items.len() as u64);
+
+    let mut hasher = Sha3_256::new();
+    hasher.update(&(items.len() as u64).to_le_bytes());
+    for item in items {
+        let bytes = match item.simple_serialize() {
+            Some(bytes) => bytes,
+            None => return Ok(NativeResult::err(cost, ESERIALIZATION_FAILURE)),
+        };
+        cost += gas_params.per_byte_cost * NumBytes::new(bytes.len() as u64);
+        hasher.update(&(bytes.len() as u64).to_le_bytes());
+        hasher.update(&bytes);
+    }
+
+    Ok(NativeResult::ok(
+        cost,
+        smallvec![Value::vector_u8(hasher.finalize().to_vec())],
+    ))
+}
+
+pub fn make_native_commit_vec(gas_params: CommitVecGasParameters) -> NativeFunction {
+    Arc::new(move |context, ty_args, args| native_commit_vec(&gas_params, context, ty_args, args))
+}
+
+/***************************************************************************************************
+ * native fun sort_u64, sort_u128
+ *
+ *   gas cost: base_cost + per_comparison_cost * n * ceil(log2(n))
+ *
+ **************************************************************************************************/
+#[derive(Debug, Clone)]
+pub struct SortGasParameters {
+    pub base_cost: InternalGas,
+    pub per_comparison_cost: InternalGasPerArg,
+}
+
+impl SortGasParameters {
+    /// Charges for the `n * ceil(log2(n))` comparisons of sorting `n` elements.
+    fn cost(&self, n: usize) -> InternalGas {
+        let n = n as u64;
+        let log_n = if n <= 1 {
+            0
+        } else {
+            64 - (n - 1).leading_zeros() as u64
+        };
+        self.base_cost + self.per_comparison_cost * NumArgs::new(n * log_n)
+    }
+}
+
+fn native_sort_u64(
+    gas_params: &SortGasParameters,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert_eq!(args.len(), 1);
+
+    let mut v = pop_arg!(args, Vec<u64>);
+    let cost = gas_params.cost(v.len());
+    v.sort_unstable();
+
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u64(v)]))
+}
+
+pub fn make_native_sort_u64(gas_params: SortGasParameters) -> NativeFunction {
+    Arc::new(move |_context, _ty_args, args| native_sort_u64(&gas_params, args))
+}
+
+fn native_sort_u128(
+    gas_params: &SortGasParameters,
+    mut args: VecDeque<Value>,
+) -> PartialVMResult<NativeResult> {
+    debug_assert_eq!(args.len(), 1);
+
+    let mut v = pop_arg!(args, Vec<u128>);
+    let cost = gas_params.cost(v.len());
+    v.sort_unstable();
+
+    Ok(NativeResult::ok(cost, smallvec![Value::vector_u128(v)]))
+}
+
+pub fn make_native_sort_u128(gas_params: SortGasParameters) -> NativeFunction {
+    Arc::new(move |_context, _ty_args, args| native_sort_u128(&gas_params, args))
+}
+
 /***************************************************************************************************
  * module
  *
@@ -106,10 +1141,123 @@ pub fn make_native_from_bytes(gas_params: FromBytesGasParameters) -> NativeFunct
 #[derive(Debug, Clone)]
 pub struct GasParameters {
     pub from_bytes: FromBytesGasParameters,
+    pub bytes_equal_constant_time: ConstantTimeEqGasParameters,
+    pub ct_select: ConstantTimeSelectGasParameters,
+    pub to_hex: ToHexGasParameters,
+    pub from_hex: FromHexGasParameters,
+    pub base64_encode: Base64EncodeGasParameters,
+    pub base64_decode: Base64DecodeGasParameters,
+    pub reverse_bytes: ReverseBytesGasParameters,
+    pub u64_to_bytes: U64ToBytesGasParameters,
+    pub u64_from_bytes: U64FromBytesGasParameters,
+    pub mul_u128_wide: MulWideGasParameters,
+    pub mod_inverse: ModInverseGasParameters,
+    pub mul_div: MulDivGasParameters,
+    pub bytes_slice: BytesSliceGasParameters,
+    pub bytes_concat: BytesConcatGasParameters,
+    pub rotate: RotateGasParameters,
+    pub bit_count: BitCountGasParameters,
+    pub shuffle_in_place: ShuffleGasParameters,
+    pub random_bytes: RandomBytesGasParameters,
+    pub commit_vec: CommitVecGasParameters,
+    pub sort: SortGasParameters,
 }
 
 pub fn make_all(gas_params: GasParameters) -> impl Iterator<Item = (String, NativeFunction)> {
-    let natives = [("from_bytes", make_native_from_bytes(gas_params.from_bytes))];
+    let natives = [
+        ("from_bytes", make_native_from_bytes(gas_params.from_bytes)),
+        (
+            "bytes_equal_constant_time",
+            make_native_bytes_equal_constant_time(gas_params.bytes_equal_constant_time),
+        ),
+        ("ct_select", make_native_ct_select(gas_params.ct_select)),
+        ("to_hex", make_native_to_hex(gas_params.to_hex)),
+        ("from_hex", make_native_from_hex(gas_params.from_hex)),
+        (
+            "base64_encode",
+            make_native_base64_encode(gas_params.base64_encode),
+        ),
+        (
+            "base64_decode",
+            make_native_base64_decode(gas_params.base64_decode),
+        ),
+        (
+            "reverse_bytes",
+            make_native_reverse_bytes(gas_params.reverse_bytes),
+        ),
+        (
+            "u64_to_le_bytes",
+            make_native_u64_to_bytes(gas_params.u64_to_bytes.clone(), false),
+        ),
+        (
+            "u64_to_be_bytes",
+            make_native_u64_to_bytes(gas_params.u64_to_bytes, true),
+        ),
+        (
+            "u64_from_le_bytes",
+            make_native_u64_from_bytes(gas_params.u64_from_bytes.clone(), false),
+        ),
+        (
+            "u64_from_be_bytes",
+            make_native_u64_from_bytes(gas_params.u64_from_bytes, true),
+        ),
+        (
+            "mul_u128_wide",
+            make_native_mul_u128_wide(gas_params.mul_u128_wide),
+        ),
+        (
+            "mod_inverse",
+            make_native_mod_inverse(gas_params.mod_inverse),
+        ),
+        ("mul_div", make_native_mul_div(gas_params.mul_div)),
+        (
+            "bytes_slice",
+            make_native_bytes_slice(gas_params.bytes_slice),
+        ),
+        (
+            "bytes_concat",
+            make_native_bytes_concat(gas_params.bytes_concat),
+        ),
+        (
+            "rotl_u64",
+            make_native_rotate_u64(gas_params.rotate.clone(), true),
+        ),
+        (
+            "rotr_u64",
+            make_native_rotate_u64(gas_params.rotate.clone(), false),
+        ),
+        (
+            "rotl_u128",
+            make_native_rotate_u128(gas_params.rotate.clone(), true),
+        ),
+        (
+            "rotr_u128",
+            make_native_rotate_u128(gas_params.rotate, false),
+        ),
+        (
+            "count_ones_u128",
+            make_native_bit_count_u128(gas_params.bit_count.clone(), u128::count_ones),
+        ),
+        (
+            "leading_zeros_u128",
+            make_native_bit_count_u128(gas_params.bit_count.clone(), u128::leading_zeros),
+        ),
+        (
+            "trailing_zeros_u128",
+            make_native_bit_count_u128(gas_params.bit_count, u128::trailing_zeros),
+        ),
+        (
+            "shuffle_in_place",
+            make_native_shuffle_in_place(gas_params.shuffle_in_place),
+        ),
+        (
+            "random_bytes",
+            make_native_random_bytes(gas_params.random_bytes),
+        ),
+        ("commit_vec", make_native_commit_vec(gas_params.commit_vec)),
+        ("sort_u64", make_native_sort_u64(gas_params.sort.clone())),
+        ("sort_u128", make_native_sort_u128(gas_params.sort)),
+    ];
 
     crate::natives::helpers::make_module_natives(natives)
 }
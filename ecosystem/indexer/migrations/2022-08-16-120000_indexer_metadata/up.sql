@@ -0,0 +1,7 @@
+-- Your SQL goes here
+CREATE TABLE indexer_metadata
+(
+    name VARCHAR(50) NOT NULL,
+    value VARCHAR(100) NOT NULL,
+    PRIMARY KEY (name)
+);
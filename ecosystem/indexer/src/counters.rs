@@ -2,7 +2,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use aptos_metrics_core::{
-    register_int_counter, register_int_counter_vec, IntCounter, IntCounterVec,
+    register_int_counter, register_int_counter_vec, register_int_gauge, IntCounter, IntCounterVec,
+    IntGauge,
 };
 use once_cell::sync::Lazy;
 
@@ -71,3 +72,12 @@ pub static FETCHED_TRANSACTION: Lazy<IntCounter> = Lazy::new(|| {
     )
     .unwrap()
 });
+
+/// Set to 1 once the indexer has caught up to the chain tip, as configured by `--caught-up-threshold`
+pub static CAUGHT_UP: Lazy<IntGauge> = Lazy::new(|| {
+    register_int_gauge!(
+        "indexer_caught_up",
+        "Set to 1 once the indexer has caught up to the chain tip"
+    )
+    .unwrap()
+});
@@ -5,12 +5,20 @@
 #![allow(clippy::extra_unused_lifetimes)]
 use std::sync::Arc;
 
+use crate::{
+    models::indexer_metadata::{IndexerMetadata, SCHEMA_VERSION_NAME},
+    schema::indexer_metadata::{self, dsl},
+};
 use diesel::{
     pg::PgConnection,
     r2d2::{ConnectionManager, PoolError, PooledConnection},
-    RunQueryDsl,
+    ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl,
 };
 
+/// The schema version this binary expects, i.e., the name of its latest migration. This must be
+/// updated whenever a migration is added.
+pub const SCHEMA_VERSION: &str = "2022-08-16-120000_indexer_metadata";
+
 pub type PgPool = diesel::r2d2::Pool<ConnectionManager<PgConnection>>;
 pub type PgDbPool = Arc<PgPool>;
 pub type PgPoolConnection = PooledConnection<ConnectionManager<PgConnection>>;
@@ -20,6 +28,70 @@ pub fn new_db_pool(database_url: &str) -> Result<PgDbPool, PoolError> {
     PgPool::builder().build(manager).map(Arc::new)
 }
 
+/// Acquires a connection from the pool and checks that it can run a trivial query, so that an
+/// unreachable database is reported at startup rather than on the first insert. When migrations
+/// are skipped, also checks that they have been run before, i.e., that the diesel migrations table
+/// exists, and that the schema version recorded by the last migration run matches `SCHEMA_VERSION`.
+pub fn check_db_health(pool: &PgDbPool, skip_migrations: bool) -> Result<(), String> {
+    let conn = pool
+        .get()
+        .map_err(|e| format!("Could not get a connection from the pool: {}", e))?;
+    diesel::sql_query("SELECT 1")
+        .execute(&conn)
+        .map_err(|e| format!("Could not run a query on the database: {}", e))?;
+    if skip_migrations {
+        diesel::sql_query("SELECT 1 FROM __diesel_schema_migrations LIMIT 1")
+            .execute(&conn)
+            .map_err(|e| {
+                format!(
+                    "The migrations table is missing, run the indexer without --skip-migrations: {}",
+                    e
+                )
+            })?;
+        let found = dsl::indexer_metadata
+            .select(dsl::value)
+            .filter(dsl::name.eq(SCHEMA_VERSION_NAME))
+            .first::<String>(&conn)
+            .optional()
+            .map_err(|e| format!("Could not read the schema version: {}", e))?;
+        if found.as_deref() != Some(SCHEMA_VERSION) {
+            return Err(format!(
+                "schema version mismatch: expected {}, found {}, run the indexer without --skip-migrations",
+                SCHEMA_VERSION,
+                found.as_deref().unwrap_or("none")
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Records `SCHEMA_VERSION` as the schema version of the database, after running the migrations.
+pub fn write_schema_version(conn: &PgPoolConnection) -> diesel::QueryResult<usize> {
+    execute_with_better_error(
+        conn,
+        diesel::insert_into(indexer_metadata::table)
+            .values(&IndexerMetadata {
+                name: SCHEMA_VERSION_NAME.to_string(),
+                value: SCHEMA_VERSION.to_string(),
+            })
+            .on_conflict(dsl::name)
+            .do_update()
+            .set(dsl::value.eq(SCHEMA_VERSION)),
+    )
+}
+
+/// Runs a user-provided SQL statement, e.g., to refresh a materialized view, on a connection from
+/// the pool. The statement runs in its own implicit transaction.
+pub fn execute_user_sql(pool: &PgDbPool, sql: &str) -> Result<(), String> {
+    let conn = pool
+        .get()
+        .map_err(|e| format!("Could not get a connection from the pool: {}", e))?;
+    diesel::sql_query(sql)
+        .execute(&conn)
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
 pub fn execute_with_better_error<
     T: diesel::Table + diesel::QuerySource,
     U: diesel::query_builder::QueryFragment<diesel::pg::Pg>
@@ -1,7 +1,7 @@
 // Copyright (c) Aptos
 // SPDX-License-Identifier: Apache-2.0
 use crate::{
-    database::{execute_with_better_error, PgDbPool},
+    database::{execute_with_better_error, write_schema_version, PgDbPool},
     indexer::{
         errors::TransactionProcessingError,
         fetcher::{TransactionFetcher, TransactionFetcherTrait},
@@ -75,14 +75,13 @@ impl Tailer {
 
     pub fn run_migrations(&self) {
         info!("Running migrations...");
-        embedded_migrations::run_with_output(
-            &self
-                .connection_pool
-                .get()
-                .expect("Could not get connection for migrations"),
-            &mut std::io::stdout(),
-        )
-        .expect("migrations failed!");
+        let conn = self
+            .connection_pool
+            .get()
+            .expect("Could not get connection for migrations");
+        embedded_migrations::run_with_output(&conn, &mut std::io::stdout())
+            .expect("migrations failed!");
+        write_schema_version(&conn).expect("Could not write the schema version!");
         info!("Migrations complete!");
     }
 
@@ -127,6 +126,16 @@ impl Tailer {
         }
     }
 
+    /// Returns the latest version of the ledger, according to the node
+    pub async fn fetch_ledger_version(&self) -> u64 {
+        self.transaction_fetcher
+            .lock()
+            .await
+            .fetch_ledger_info()
+            .await
+            .version
+    }
+
     pub fn add_processor(&mut self, processor: Arc<dyn TransactionProcessor>) {
         info!("Adding processor to indexer: {}", processor.name());
         self.processors.push(processor);
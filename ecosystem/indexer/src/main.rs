@@ -7,12 +7,22 @@
 //!
 #![forbid(unsafe_code)]
 
-use aptos_logger::info;
+use aptos_logger::{info, warn, Level};
 use clap::Parser;
-use std::sync::Arc;
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
 use aptos_indexer::{
-    database::new_db_pool, default_processor::DefaultTransactionProcessor, indexer::tailer::Tailer,
+    counters::CAUGHT_UP,
+    database::{check_db_health, execute_user_sql, new_db_pool},
+    default_processor::DefaultTransactionProcessor,
+    indexer::tailer::Tailer,
     token_processor::TokenTransactionProcessor,
 };
 
@@ -54,6 +64,33 @@ struct IndexerArgs {
     #[clap(long, default_value_t = 1000)]
     emit_every: usize,
 
+    /// If set, log a summary of the indexing progress (versions processed and throughput since the
+    /// last summary, and the current version) every this many seconds.
+    #[clap(long)]
+    stats_interval_secs: Option<u64>,
+
+    /// If set, exit after processing this many versions in this run, e.g., to backfill in chunks.
+    /// The next run resumes from the lowest version processed by all processors.
+    /// This will only be checked every `--batch-size` number of versions.
+    #[clap(long)]
+    max_versions_this_run: Option<usize>,
+
+    /// If set, the indexing loop rewrites this JSON file with the current version and the time of
+    /// the update after every batch, e.g., for liveness probes
+    #[clap(long, parse(from_os_str))]
+    status_file: Option<PathBuf>,
+
+    /// If set, log "Caught up to chain tip" and set the `indexer_caught_up` gauge to 1 the first
+    /// time the indexer is fewer than this many versions behind the latest ledger version.
+    /// Until then, the ledger version is fetched from the node after every batch.
+    #[clap(long)]
+    caught_up_threshold: Option<u64>,
+
+    /// If set, run this SQL statement after every batch, once all its transactions are committed,
+    /// e.g., "REFRESH MATERIALIZED VIEW CONCURRENTLY my_view". Failures are logged, not fatal.
+    #[clap(long)]
+    post_commit_sql: Option<String>,
+
     /// Turn on the indexer to collect token, ownership, collection and metadata and store them
     /// in the postgres DB tables.
     #[clap(long)]
@@ -62,17 +99,35 @@ struct IndexerArgs {
     /// turn on the token URI fetcher
     #[clap(long)]
     index_token_uri_data: bool,
+
+    /// Log level, one of error, warn, info, debug or trace.
+    /// If the `RUST_LOG` environment variable is set, it takes precedence over this flag.
+    #[clap(long, default_value = "info", parse(try_from_str = parse_log_level))]
+    log_level: Level,
+}
+
+fn parse_log_level(level: &str) -> Result<Level, String> {
+    level.parse().map_err(|_| {
+        format!(
+            "invalid log level '{}', expected one of error, warn, info, debug or trace",
+            level
+        )
+    })
 }
 
 #[tokio::main]
 async fn main() -> std::io::Result<()> {
-    aptos_logger::Logger::new().init();
     let args: IndexerArgs = IndexerArgs::parse();
+    aptos_logger::Logger::new().level(args.log_level).init();
 
     info!("Starting indexer...");
 
     let conn_pool = new_db_pool(&args.pg_uri).unwrap();
     info!("Created the connection pool... ");
+    if let Err(e) = check_db_health(&conn_pool, args.skip_migrations) {
+        panic!("Database health check failed: {}", e);
+    }
+    info!("Database health check passed");
 
     let mut tailer = Tailer::new(&args.node_url, conn_pool.clone()).unwrap();
 
@@ -106,9 +161,42 @@ async fn main() -> std::io::Result<()> {
     info!("Indexing loop started!");
     let mut processed: usize = starting_version as usize;
     let mut base: usize = 0;
+    let mut caught_up = false;
+    let processed_counter = Arc::new(AtomicUsize::new(processed));
+    if let Some(stats_interval_secs) = args.stats_interval_secs {
+        tokio::spawn(log_stats(processed_counter.clone(), stats_interval_secs));
+    }
     loop {
         let res = tailer.process_next_batch(args.batch_size).await;
         processed += res.len();
+        processed_counter.store(processed, Ordering::Relaxed);
+        if let Some(threshold) = args.caught_up_threshold {
+            if !caught_up {
+                let ledger_version = tailer.fetch_ledger_version().await;
+                if (ledger_version + 1).saturating_sub(processed as u64) < threshold {
+                    caught_up = true;
+                    CAUGHT_UP.set(1);
+                    info!(
+                        "Caught up to chain tip: processed {} versions, ledger version is {}",
+                        processed, ledger_version
+                    );
+                }
+            }
+        }
+        if let Some(sql) = &args.post_commit_sql {
+            if let Err(e) = execute_user_sql(&conn_pool, sql) {
+                warn!("Failed to run post-commit SQL '{}': {}", sql, e);
+            }
+        }
+        if let Some(status_file) = &args.status_file {
+            if let Err(e) = write_status_file(status_file, processed) {
+                warn!(
+                    "Failed to write status file {}: {}",
+                    status_file.display(),
+                    e
+                );
+            }
+        }
         if args.emit_every != 0 {
             let new_base: usize = processed / args.emit_every;
             if base != new_base {
@@ -116,5 +204,51 @@ async fn main() -> std::io::Result<()> {
                 aptos_logger::info!("Indexer has processed {} versions", processed);
             }
         }
+        if let Some(max_versions) = args.max_versions_this_run {
+            if processed - starting_version as usize >= max_versions {
+                info!(
+                    "Processed {} versions in this run, exiting! The next run resumes from version {}",
+                    processed - starting_version as usize,
+                    processed
+                );
+                return Ok(());
+            }
+        }
     }
 }
+
+/// Periodically logs how many versions were processed since the last summary, and the current
+/// version, as tracked by the indexing loop in `processed`.
+async fn log_stats(processed: Arc<AtomicUsize>, interval_secs: u64) {
+    let interval_secs = interval_secs.max(1);
+    let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+    // The first tick completes immediately
+    interval.tick().await;
+    let mut last_processed = processed.load(Ordering::Relaxed);
+    loop {
+        interval.tick().await;
+        let current_processed = processed.load(Ordering::Relaxed);
+        let delta = current_processed - last_processed;
+        info!(
+            "Indexer stats: processed {} versions in the last {}s ({:.1} versions/s), current version {}",
+            delta,
+            interval_secs,
+            delta as f64 / interval_secs as f64,
+            current_processed
+        );
+        last_processed = current_processed;
+    }
+}
+
+/// Atomically replaces the status file with `{ version, updated_at }`, by writing a temporary file
+/// next to it and renaming it, so that readers never observe a partial write.
+fn write_status_file(path: &Path, version: usize) -> std::io::Result<()> {
+    let status = serde_json::json!({
+        "version": version,
+        "updated_at": chrono::Utc::now(),
+    });
+    let mut tmp_path = path.as_os_str().to_owned();
+    tmp_path.push(".tmp");
+    std::fs::write(&tmp_path, status.to_string())?;
+    std::fs::rename(&tmp_path, path)
+}
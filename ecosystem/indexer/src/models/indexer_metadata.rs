@@ -0,0 +1,14 @@
+// Copyright (c) Aptos
+// SPDX-License-Identifier: Apache-2.0
+#![allow(clippy::extra_unused_lifetimes)]
+use crate::schema::indexer_metadata as indexer_metadatas;
+
+/// Name of the `indexer_metadata` row holding the schema version of the database
+pub const SCHEMA_VERSION_NAME: &str = "schema_version";
+
+#[derive(Debug, Insertable, Queryable)]
+#[diesel(table_name = indexer_metadata)]
+pub struct IndexerMetadata {
+    pub name: String,
+    pub value: String,
+}
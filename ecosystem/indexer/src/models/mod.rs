@@ -3,6 +3,7 @@
 
 pub mod collection;
 pub mod events;
+pub mod indexer_metadata;
 pub mod ledger_info;
 pub mod metadata;
 pub mod ownership;
@@ -41,6 +41,13 @@ table! {
     }
 }
 
+table! {
+    indexer_metadata (name) {
+        name -> Varchar,
+        value -> Varchar,
+    }
+}
+
 table! {
     ledger_infos (chain_id) {
         chain_id -> Int8,
@@ -183,6 +190,7 @@ allow_tables_to_appear_in_same_query!(
     block_metadata_transactions,
     collections,
     events,
+    indexer_metadata,
     ledger_infos,
     metadatas,
     ownerships,
@@ -26,6 +26,19 @@ pub fn from_identity_public_key(identity_public_key: x25519::PublicKey) -> Accou
     AccountAddress::new(array)
 }
 
+/// Scheme byte appended to the input when deriving the address of an object, which separates object
+/// addresses from the other derived addresses.
+pub const OBJECT_ADDRESS_SCHEME: u8 = 0xFE;
+
+/// Derives the address of an object created by `creator` from `seed`, i.e.,
+/// `sha3_256(creator | seed | OBJECT_ADDRESS_SCHEME)`.
+pub fn create_object_address(creator: AccountAddress, seed: &[u8]) -> AccountAddress {
+    let mut input = creator.to_vec();
+    input.extend(seed);
+    input.push(OBJECT_ADDRESS_SCHEME);
+    AccountAddress::new(*HashValue::sha3_256_of(&input))
+}
+
 // Define the Hasher used for hashing AccountAddress types. In order to properly use the
 // CryptoHasher derive macro we need to have this in its own module so that it doesn't conflict
 // with the imported `AccountAddress` from move-core-types. It needs to have the same name since
@@ -49,7 +62,7 @@ impl HashAccountAddress for AccountAddress {
 
 #[cfg(test)]
 mod test {
-    use super::{AccountAddress, HashAccountAddress};
+    use super::{create_object_address, AccountAddress, HashAccountAddress};
     use aptos_crypto::hash::HashValue;
     use hex::FromHex;
 
@@ -67,4 +80,16 @@ mod test {
         hash.copy_from_slice(bytes);
         assert_eq!(address.hash(), HashValue::new(hash));
     }
+
+    #[test]
+    fn object_address() {
+        let creator = AccountAddress::from_hex_literal("0xcafe").unwrap();
+        assert_eq!(
+            create_object_address(creator, b"seed"),
+            AccountAddress::from_hex_literal(
+                "0x13d1c291f92b76cbc84a2dafa1c1fc57bf0500ada5d89d3dc8a07641233dd7db"
+            )
+            .unwrap()
+        );
+    }
 }